@@ -0,0 +1,68 @@
+use miette::IntoDiagnostic;
+use uplc_turbo::{
+    binder::DeBruijn, data::PlutusData, flat, machine::PlutusVersion, program::Program,
+};
+
+use super::parse_plutus_version;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Script as flat or CBOR wrapped flat, raw or hex encoded
+    script: String,
+    /// CBOR hex encoded data, applied in the order given
+    #[clap(short, long = "param")]
+    params: Vec<String>,
+    /// Write the CBOR hex encoded script here instead of stdout
+    #[clap(short, long)]
+    out: Option<String>,
+    /// Plutus version used for the script hash
+    #[clap(short = 'v', long, default_value = "v3")]
+    plutus_version: String,
+}
+
+impl Args {
+    pub fn exec(self) -> miette::Result<()> {
+        let arena = uplc_turbo::bumpalo::Bump::with_capacity(1_024_000);
+
+        let version: PlutusVersion =
+            parse_plutus_version(&self.plutus_version).map_err(|e| miette::miette!("{}", e))?;
+
+        let bytes = std::fs::read(&self.script).into_diagnostic()?;
+
+        let program: &Program<DeBruijn> = match std::str::from_utf8(&bytes) {
+            Ok(text) if hex::decode(text.trim()).is_ok() => {
+                flat::decode_hex(&arena, text).into_diagnostic()?
+            }
+            _ => flat::decode_cbor(&arena, &bytes).into_diagnostic()?,
+        };
+
+        let mut params = Vec::with_capacity(self.params.len());
+
+        for (index, param) in self.params.iter().enumerate() {
+            let cbor = hex::decode(param.trim())
+                .map_err(|e| miette::miette!("parameter {} is not valid hex: {e}", index + 1))?;
+
+            let data = PlutusData::from_cbor(&arena, &cbor)
+                .map_err(|e| miette::miette!("parameter {} is not valid data: {e}", index + 1))?;
+
+            params.push(data);
+        }
+
+        let program = program.apply_params(&arena, &params);
+
+        let script = hex::encode(flat::encode_cbor(program).into_diagnostic()?);
+
+        let hash = hex::encode(program.hash(version).into_diagnostic()?);
+
+        if let Some(out) = self.out {
+            std::fs::write(out, &script).into_diagnostic()?;
+        } else {
+            println!("{script}");
+        }
+
+        // stdout only gets the script so it can be redirected to a file
+        eprintln!("hash: {hash}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use miette::IntoDiagnostic;
+use uplc_turbo::machine::CostModel;
+
+use super::parse_plutus_version;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Write the cost model here instead of stdout
+    #[clap(short, long)]
+    out: Option<String>,
+    #[clap(short = 'v', long, default_value = "v3")]
+    plutus_version: String,
+}
+
+impl Args {
+    pub fn exec(self) -> miette::Result<()> {
+        let version =
+            parse_plutus_version(&self.plutus_version).map_err(|e| miette::miette!("{}", e))?;
+
+        let json = CostModel::for_version(version).to_json();
+
+        if let Some(out) = self.out {
+            std::fs::write(out, json).into_diagnostic()?;
+        } else {
+            println!("{json}");
+        }
+
+        Ok(())
+    }
+}
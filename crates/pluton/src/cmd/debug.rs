@@ -0,0 +1,482 @@
+use std::io::{self, BufRead, Write};
+
+use miette::IntoDiagnostic;
+use uplc_turbo::{
+    binder::DeBruijn,
+    builtin::DefaultFunction,
+    bumpalo::Bump,
+    machine::{Context, Machine, MachineState, ProtocolVersion, Value},
+    program::EvalOptions,
+    term::Term,
+};
+
+use super::{parse_plutus_version, Input};
+
+const HELP: &str = "\
+s, step [n]        take n steps, 1 by default
+n, next            step over the current term
+c, continue        run until a breakpoint, an error or the end
+b, break <name>    break before calling the builtin <name>, or on `error`
+d, delete <name>   remove a breakpoint
+p, print           print the current term or value
+e, env             print the environment, most recent binding first
+bt, stack          print the continuation frames, innermost first
+budget             print the remaining budget
+h, help            print this message
+q, quit            stop debugging";
+
+/// Terms and values are cut to this many characters outside of `print`.
+const SHORT_WIDTH: usize = 100;
+
+#[derive(clap::Args)]
+pub struct Args {
+    #[clap(flatten)]
+    input: Input,
+    #[clap(short = 'v', long)]
+    plutus_version: Option<String>,
+    /// Ledger protocol major version, deciding which builtins are enabled
+    #[clap(long)]
+    protocol_version: Option<u64>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Command<'s> {
+    Step(usize),
+    Next,
+    Continue,
+    Break(&'s str),
+    Delete(&'s str),
+    Print,
+    Env,
+    Stack,
+    Budget,
+    Help,
+    Quit,
+}
+
+#[derive(Debug, PartialEq)]
+enum Breakpoint {
+    Builtin(DefaultFunction),
+    Error,
+}
+
+struct Debugger<'a> {
+    arena: &'a Bump,
+    machine: Machine<'a>,
+    state: MachineState<'a, DeBruijn>,
+    /// Set once evaluation failed, the state is then the one that failed.
+    error: Option<String>,
+    breakpoints: Vec<Breakpoint>,
+    printed_logs: usize,
+}
+
+impl Args {
+    pub fn exec(self) -> miette::Result<()> {
+        let arena = Bump::with_capacity(1_024_000);
+
+        let program = self.input.program(&arena)?;
+
+        let mut options = match self.plutus_version {
+            Some(version_str) => EvalOptions::for_version(
+                parse_plutus_version(&version_str).map_err(|e| miette::miette!("{}", e))?,
+            ),
+            None => EvalOptions::default(),
+        };
+
+        if let Some(protocol_version) = self.protocol_version {
+            options.protocol_version = ProtocolVersion(protocol_version);
+        }
+
+        let mut machine = Machine::new(&arena, &options, program.version);
+
+        let state = machine
+            .start(program.term)
+            .map_err(|e| miette::miette!("{}", e))?;
+
+        let mut debugger = Debugger {
+            arena: &arena,
+            machine,
+            state,
+            error: None,
+            breakpoints: Vec::new(),
+            printed_logs: 0,
+        };
+
+        debugger.print_state();
+
+        let stdin = io::stdin();
+        let mut lines = stdin.lock().lines();
+
+        loop {
+            print!("(pluton) ");
+
+            io::stdout().flush().into_diagnostic()?;
+
+            let Some(line) = lines.next() else {
+                println!();
+
+                return Ok(());
+            };
+
+            let line = line.into_diagnostic()?;
+
+            let command = match parse_command(&line) {
+                Ok(Some(command)) => command,
+                Ok(None) => continue,
+                Err(message) => {
+                    println!("{message}");
+
+                    continue;
+                }
+            };
+
+            match command {
+                Command::Step(count) => {
+                    debugger.run(|debugger, steps| steps >= count || debugger.at_breakpoint());
+                }
+                Command::Next => {
+                    let depth = debugger.depth();
+
+                    debugger.run(|debugger, _| {
+                        (debugger.state.value().is_some() && debugger.depth() <= depth)
+                            || debugger.at_breakpoint()
+                    });
+                }
+                Command::Continue => debugger.run(|debugger, _| debugger.at_breakpoint()),
+                Command::Break(name) => match breakpoint(&arena, name) {
+                    Some(breakpoint) if !debugger.breakpoints.contains(&breakpoint) => {
+                        debugger.breakpoints.push(breakpoint)
+                    }
+                    Some(_) => println!("already breaking on {name}"),
+                    None => println!("unknown builtin {name}"),
+                },
+                Command::Delete(name) => match breakpoint(&arena, name) {
+                    Some(breakpoint) if debugger.breakpoints.contains(&breakpoint) => {
+                        debugger.breakpoints.retain(|b| *b != breakpoint)
+                    }
+                    _ => println!("not breaking on {name}"),
+                },
+                Command::Print => debugger.print_current(),
+                Command::Env => debugger.print_env(),
+                Command::Stack => debugger.print_stack(),
+                Command::Budget => debugger.print_budget(),
+                Command::Help => println!("{HELP}"),
+                Command::Quit => return Ok(()),
+            }
+        }
+    }
+}
+
+impl<'a> Debugger<'a> {
+    /// Steps until `stop` holds after a step, evaluation ends or fails.
+    fn run(&mut self, stop: impl Fn(&Self, usize) -> bool) {
+        if let Some(error) = &self.error {
+            println!("evaluation failed: {error}");
+
+            return;
+        }
+
+        if self.state.is_done() {
+            println!("evaluation finished");
+
+            return;
+        }
+
+        let mut steps = 0;
+
+        loop {
+            match self.machine.step(self.state) {
+                Ok(state) => self.state = state,
+                Err(error) => self.error = Some(error.to_string()),
+            }
+
+            steps += 1;
+
+            self.print_logs();
+
+            if self.error.is_some() || self.state.is_done() || stop(self, steps) {
+                break;
+            }
+        }
+
+        self.print_state();
+    }
+
+    fn depth(&self) -> usize {
+        self.state
+            .context()
+            .map_or(0, |context| context.frames().count())
+    }
+
+    fn at_breakpoint(&self) -> bool {
+        at_breakpoint(self.state, &self.breakpoints)
+    }
+
+    fn print_state(&self) {
+        match self.state {
+            MachineState::Compute(_, _, term) => println!("compute {}", short(term)),
+            MachineState::Return(_, value) => {
+                println!("return {}", short(value.to_term(self.arena)))
+            }
+            MachineState::Done(term) => println!("done {}", short(term)),
+        }
+
+        if let Some(error) = &self.error {
+            println!("error: {error}");
+        }
+
+        self.print_budget();
+    }
+
+    fn print_current(&self) {
+        match self.state {
+            MachineState::Compute(_, _, term) | MachineState::Done(term) => {
+                println!("{}", term.to_pretty())
+            }
+            MachineState::Return(_, value) => println!("{}", value.to_term(self.arena).to_pretty()),
+        }
+    }
+
+    fn print_env(&self) {
+        let Some(env) = self.state.env() else {
+            println!("only computing terms have an environment");
+
+            return;
+        };
+
+        for (index, value) in env.iter().enumerate() {
+            println!("{}: {}", index + 1, short(value.to_term(self.arena)));
+        }
+    }
+
+    fn print_stack(&self) {
+        let Some(context) = self.state.context() else {
+            return;
+        };
+
+        for frame in context.frames() {
+            let frame = match frame {
+                Context::FrameAwaitArg(function, _) => {
+                    format!("[{} _]", short(function.to_term(self.arena)))
+                }
+                Context::FrameAwaitFunTerm(_, argument, _) => format!("[_ {}]", short(argument)),
+                Context::FrameAwaitFunValue(argument, _) => {
+                    format!("[_ {}]", short(argument.to_term(self.arena)))
+                }
+                Context::FrameForce(_) => "(force _)".to_string(),
+                Context::FrameConstr(_, tag, terms, values, _) => format!(
+                    "(constr {tag} with {} evaluated and {} to go)",
+                    values.len(),
+                    terms.len()
+                ),
+                Context::FrameCases(_, branches, _) => {
+                    format!("(case _ with {} branches)", branches.len())
+                }
+                Context::NoFrame => "top".to_string(),
+            };
+
+            println!("{frame}");
+        }
+    }
+
+    fn print_budget(&self) {
+        let budget = self.machine.remaining_budget();
+
+        println!("remaining cpu: {} mem: {}", budget.cpu, budget.mem);
+    }
+
+    fn print_logs(&mut self) {
+        for log in &self.machine.logs()[self.printed_logs..] {
+            println!("trace: {log}");
+        }
+
+        self.printed_logs = self.machine.logs().len();
+    }
+}
+
+/// Parses a line of input, blank lines are no command at all.
+fn parse_command(line: &str) -> Result<Option<Command<'_>>, String> {
+    let mut words = line.split_whitespace();
+
+    let command = match (words.next(), words.next()) {
+        (None, _) => return Ok(None),
+        (Some("s" | "step"), None) => Command::Step(1),
+        (Some("s" | "step"), Some(count)) => Command::Step(
+            count
+                .parse()
+                .map_err(|_| "step takes a number of steps".to_string())?,
+        ),
+        (Some("n" | "next"), _) => Command::Next,
+        (Some("c" | "continue"), _) => Command::Continue,
+        (Some("b" | "break"), Some(name)) => Command::Break(name),
+        (Some("d" | "delete"), Some(name)) => Command::Delete(name),
+        (Some(command @ ("b" | "break" | "d" | "delete")), None) => {
+            return Err(format!("{command} takes a builtin name or `error`"))
+        }
+        (Some("p" | "print"), _) => Command::Print,
+        (Some("e" | "env"), _) => Command::Env,
+        (Some("bt" | "stack"), _) => Command::Stack,
+        (Some("budget"), _) => Command::Budget,
+        (Some("h" | "help"), _) => Command::Help,
+        (Some("q" | "quit"), _) => Command::Quit,
+        (Some(command), _) => return Err(format!("unknown command {command}, try `help`")),
+    };
+
+    Ok(Some(command))
+}
+
+/// The breakpoint `name` stands for, a builtin or `error`.
+fn breakpoint(arena: &Bump, name: &str) -> Option<Breakpoint> {
+    if name == "error" {
+        return Some(Breakpoint::Error);
+    }
+
+    let builtin = format!("(builtin {name})");
+
+    match uplc_turbo::syn::parse_term_strict(arena, arena.alloc_str(&builtin)).into_result() {
+        Ok(Term::Builtin(fun)) => Some(Breakpoint::Builtin(**fun)),
+        _ => None,
+    }
+}
+
+/// Whether the next step from `state` hits one of `breakpoints`, either
+/// computing `error` or calling a builtin with its last argument.
+fn at_breakpoint(state: MachineState<'_, DeBruijn>, breakpoints: &[Breakpoint]) -> bool {
+    match state {
+        MachineState::Compute(_, _, Term::Error) => breakpoints.contains(&Breakpoint::Error),
+        MachineState::Return(context, value) => {
+            let function = match context {
+                Context::FrameAwaitArg(function, _) => function,
+                Context::FrameAwaitFunValue(_, _) => value,
+                _ => return false,
+            };
+
+            match function {
+                Value::Builtin(runtime)
+                    if !runtime.needs_force() && runtime.args.len() + 1 == runtime.fun.arity() =>
+                {
+                    breakpoints.contains(&Breakpoint::Builtin(*runtime.fun))
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// The term on one line, cut to [`SHORT_WIDTH`] characters.
+fn short(term: &Term<'_, DeBruijn>) -> String {
+    let line = term
+        .to_pretty()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    match line.char_indices().nth(SHORT_WIDTH) {
+        Some((end, _)) => format!("{}...", &line[..end]),
+        None => line,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uplc_turbo::{
+        binder::DeBruijn,
+        builtin::DefaultFunction,
+        bumpalo::Bump,
+        machine::{Machine, MachineState},
+        program::{EvalOptions, Program},
+        syn,
+    };
+
+    use super::{at_breakpoint, breakpoint, parse_command, Breakpoint, Command};
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(parse_command("  "), Ok(None));
+        assert_eq!(parse_command("s"), Ok(Some(Command::Step(1))));
+        assert_eq!(parse_command("step 12"), Ok(Some(Command::Step(12))));
+        assert_eq!(parse_command("c"), Ok(Some(Command::Continue)));
+        assert_eq!(
+            parse_command("b addInteger"),
+            Ok(Some(Command::Break("addInteger")))
+        );
+        assert_eq!(
+            parse_command("delete error"),
+            Ok(Some(Command::Delete("error")))
+        );
+        assert_eq!(parse_command("bt"), Ok(Some(Command::Stack)));
+
+        assert!(parse_command("step many").is_err());
+        assert!(parse_command("break").is_err());
+        assert!(parse_command("jump").is_err());
+    }
+
+    #[test]
+    fn parses_breakpoints() {
+        let arena = Bump::new();
+
+        assert_eq!(breakpoint(&arena, "error"), Some(Breakpoint::Error));
+        assert_eq!(
+            breakpoint(&arena, "addInteger"),
+            Some(Breakpoint::Builtin(DefaultFunction::AddInteger))
+        );
+        assert_eq!(breakpoint(&arena, "addInt"), None);
+    }
+
+    /// The states the program goes through until it is done or fails.
+    fn states<'a>(arena: &'a Bump, program: &'a str) -> Vec<MachineState<'a, DeBruijn>> {
+        let program: &Program<DeBruijn> = syn::parse_program(arena, program).into_result().unwrap();
+
+        let options = arena.alloc(EvalOptions::default());
+
+        let mut machine = Machine::new(arena, options, program.version);
+
+        let mut states = vec![machine.start(program.term).unwrap()];
+
+        while let Ok(state) = machine.step(*states.last().unwrap()) {
+            states.push(state);
+
+            if state.is_done() {
+                break;
+            }
+        }
+
+        states
+    }
+
+    #[test]
+    fn breaks_before_saturated_builtin_calls() {
+        let arena = Bump::new();
+
+        let states = states(
+            &arena,
+            "(program 1.0.0 [(builtin addInteger) (con integer 1) (con integer 2)])",
+        );
+
+        let add = [Breakpoint::Builtin(DefaultFunction::AddInteger)];
+        let sub = [Breakpoint::Builtin(DefaultFunction::SubtractInteger)];
+
+        let hits: Vec<_> = states
+            .iter()
+            .filter(|state| at_breakpoint(**state, &add))
+            .collect();
+
+        assert_eq!(hits.len(), 1);
+        assert!(matches!(hits[0], MachineState::Return(_, _)));
+
+        assert!(!states.iter().any(|state| at_breakpoint(*state, &sub)));
+    }
+
+    #[test]
+    fn breaks_before_errors() {
+        let arena = Bump::new();
+
+        let states = states(&arena, "(program 1.0.0 [(lam x (error)) (con integer 1)])");
+
+        assert!(states
+            .iter()
+            .any(|state| at_breakpoint(*state, &[Breakpoint::Error])));
+        assert!(!states.iter().any(|state| at_breakpoint(*state, &[])));
+    }
+}
@@ -1,28 +1,81 @@
-use std::io::{self, Read};
-
 use miette::IntoDiagnostic;
-use uplc_turbo::machine::PlutusVersion;
+use uplc_turbo::{
+    machine::{CostModel, ProtocolVersion, Tallied},
+    program::EvalOptions,
+};
+
+use super::{parse_plutus_version, Input};
 
 #[derive(clap::Args)]
 pub struct Args {
-    #[clap(short, long)]
-    file: Option<String>,
-    #[clap(long)]
-    flat: bool,
-    #[clap(short = 'A', long)]
-    args: Vec<String>,
+    #[clap(flatten)]
+    input: Input,
     #[clap(short = 'v', long)]
     plutus_version: Option<String>,
+    /// Print the result as JSON
+    #[clap(long)]
+    json: bool,
+    /// Initial cpu budget, defaults to the machine's 10B
+    #[clap(long)]
+    budget_cpu: Option<i64>,
+    /// Initial mem budget, defaults to the machine's 14M
+    #[clap(long)]
+    budget_mem: Option<i64>,
+    /// Ledger protocol major version, deciding which builtins are enabled
+    #[clap(long)]
+    protocol_version: Option<u64>,
+    /// Cost model JSON to evaluate with, see `pluton cost-model`
+    #[clap(long)]
+    cost_model: Option<String>,
+    /// Break the consumed budget down per step kind and builtin
+    #[clap(long)]
+    tally: bool,
+}
+
+/// The `--json` output, fields are only ever added to keep it diffable across versions.
+#[derive(serde::Serialize)]
+struct JsonOutput {
+    success: bool,
+    /// The evaluated term, pretty printed, when evaluation succeeded.
+    result: Option<String>,
+    /// The machine error message, when evaluation failed.
+    error: Option<String>,
+    budget: JsonBudget,
+    logs: Vec<String>,
+    /// The `--tally` breakdown, steps in machine order and builtins most cpu first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tally: Option<JsonTally>,
+}
+
+#[derive(serde::Serialize)]
+struct JsonBudget {
+    cpu: i64,
+    mem: i64,
+}
+
+#[derive(serde::Serialize)]
+struct JsonTally {
+    startup: JsonBudget,
+    steps: Vec<JsonTallied>,
+    builtins: Vec<JsonTallied>,
+}
+
+#[derive(serde::Serialize)]
+struct JsonTallied {
+    name: String,
+    count: u64,
+    cpu: i64,
+    mem: i64,
 }
 
-fn parse_plutus_version(s: &str) -> Result<PlutusVersion, String> {
-    match s.to_lowercase().as_str() {
-        "v1" => Ok(PlutusVersion::V1),
-        "v2" => Ok(PlutusVersion::V2),
-        "v3" => Ok(PlutusVersion::V3),
-        _ => Err(format!(
-            "Unknown Plutus version: '{s}'. Valid options: v1, v2, v3"
-        )),
+impl JsonTallied {
+    fn new(name: impl ToString, tallied: &Tallied) -> Self {
+        JsonTallied {
+            name: name.to_string(),
+            count: tallied.count,
+            cpu: tallied.budget.cpu,
+            mem: tallied.budget.mem,
+        }
     }
 }
 
@@ -30,79 +83,120 @@ impl Args {
     pub fn exec(self) -> miette::Result<()> {
         let arena = uplc_turbo::bumpalo::Bump::with_capacity(1_024_000);
 
-        let program = if let Some(file_path) = self.file {
-            std::fs::read(file_path).into_diagnostic()?
-        } else {
-            let mut buffer = Vec::new();
-
-            io::stdin().read(&mut buffer).into_diagnostic()?;
+        let program = self.input.program(&arena)?;
 
-            buffer
+        let mut options = match self.plutus_version {
+            Some(version_str) => EvalOptions::for_version(
+                parse_plutus_version(&version_str).map_err(|e| miette::miette!("{}", e))?,
+            ),
+            None => EvalOptions::default(),
         };
 
-        let mut program_string = String::new();
-
-        let program = if self.flat {
-            uplc_turbo::flat::decode(&arena, &program).into_diagnostic()?
-        } else {
-            {
-                let temp = String::from_utf8(program).into_diagnostic()?;
+        if let Some(cpu) = self.budget_cpu {
+            options.budget.cpu = cpu;
+        }
 
-                program_string.push_str(&temp);
-            }
+        if let Some(mem) = self.budget_mem {
+            options.budget.mem = mem;
+        }
 
-            let parse_result =
-                uplc_turbo::syn::parse_program(&arena, &program_string).into_result();
+        if let Some(protocol_version) = self.protocol_version {
+            options.protocol_version = ProtocolVersion(protocol_version);
+        }
 
-            match parse_result {
-                Ok(program) => program,
-                Err(errs) => {
-                    let errs = errs
-                        .into_iter()
-                        .map(|e| format!("{e}"))
-                        .collect::<Vec<_>>()
-                        .join("\n");
+        if let Some(path) = self.cost_model {
+            let json = std::fs::read_to_string(path).into_diagnostic()?;
 
-                    miette::bail!("failed to parse program\n{}", errs);
-                }
-            }
-        };
+            options.cost_model =
+                CostModel::from_json(options.plutus_version, &json).into_diagnostic()?;
+        }
 
-        let mut parsed_args = vec![];
+        options.tally = self.tally;
 
-        for (index, arg) in self.args.iter().enumerate() {
-            let parse_result = uplc_turbo::syn::parse_term(&arena, arg).into_result();
+        let eval_result = program.eval_with(&arena, options);
 
-            let term = match parse_result {
-                Ok(term) => term,
-                Err(errs) => {
-                    let errs = errs
-                        .into_iter()
-                        .map(|e| format!("{e}"))
-                        .collect::<Vec<_>>()
-                        .join("\n");
+        if self.json {
+            let (result, error) = match eval_result.term {
+                Ok(term) => (Some(term.to_pretty()), None),
+                Err(err) => (None, Some(err.to_string())),
+            };
 
-                    miette::bail!("failed to parse argument {}: {}\n{}", index + 1, arg, errs);
-                }
+            let output = JsonOutput {
+                success: result.is_some(),
+                result,
+                error,
+                budget: JsonBudget {
+                    cpu: eval_result.info.consumed_budget.cpu,
+                    mem: eval_result.info.consumed_budget.mem,
+                },
+                logs: eval_result.info.logs,
+                tally: eval_result.info.tally.map(|tally| JsonTally {
+                    startup: JsonBudget {
+                        cpu: tally.startup.cpu,
+                        mem: tally.startup.mem,
+                    },
+                    steps: tally
+                        .steps
+                        .iter()
+                        .map(|(kind, tallied)| JsonTallied::new(kind, tallied))
+                        .collect(),
+                    builtins: tally
+                        .builtins
+                        .iter()
+                        .map(|(fun, tallied)| JsonTallied::new(fun, tallied))
+                        .collect(),
+                }),
             };
 
-            parsed_args.push(term);
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&output).into_diagnostic()?
+            );
+
+            return Ok(());
         }
 
-        let program = parsed_args
-            .into_iter()
-            .fold(program, |program, arg| program.apply(&arena, arg));
+        match eval_result.term {
+            Ok(term) => println!("{term}"),
+            Err(err) => println!("error: {err}"),
+        }
 
-        if let Some(version_str) = self.plutus_version {
-            let version =
-                parse_plutus_version(&version_str).map_err(|e| miette::miette!("{}", e))?;
+        println!();
+        println!("cpu: {}", eval_result.info.consumed_budget.cpu);
+        println!("mem: {}", eval_result.info.consumed_budget.mem);
+
+        if let Some(tally) = eval_result.info.tally {
+            println!();
+            println!("{:<32} {:>8} {:>14} {:>10}", "", "count", "cpu", "mem");
+            println!(
+                "{:<32} {:>8} {:>14} {:>10}",
+                "startup", 1, tally.startup.cpu, tally.startup.mem
+            );
+
+            let steps = tally
+                .steps
+                .iter()
+                .map(|(kind, tallied)| (format!("step {kind}"), tallied));
+
+            let builtins = tally
+                .builtins
+                .iter()
+                .map(|(fun, tallied)| (format!("builtin {fun}"), tallied));
+
+            for (name, tallied) in steps.chain(builtins) {
+                println!(
+                    "{:<32} {:>8} {:>14} {:>10}",
+                    name, tallied.count, tallied.budget.cpu, tallied.budget.mem
+                );
+            }
+        }
 
-            let eval_result = program.eval_version(&arena, version);
-            println!("{eval_result:#?}");
-        } else {
-            let eval_result = program.eval(&arena);
+        if !eval_result.info.logs.is_empty() {
+            println!();
 
-            println!("{eval_result:#?}");
+            for log in eval_result.info.logs {
+                println!("{log}");
+            }
         }
 
         Ok(())
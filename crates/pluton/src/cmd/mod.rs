@@ -1,12 +1,34 @@
+use std::io::{self, Read};
+
 use clap::Parser;
+use miette::IntoDiagnostic;
+use uplc_turbo::{
+    binder::{DeBruijn, NamedDeBruijn},
+    bumpalo::Bump,
+    machine::PlutusVersion,
+    program::Program,
+    term::Term,
+};
 
+mod apply;
+mod cost_model;
+mod debug;
 mod eval;
+mod profile;
 
 /// Pluton a swiss army knife for Untyped Plutus Core
 #[derive(Parser)]
 pub enum Cmd {
     /// Evaluate an Untyped Plutus Core program
     Eval(eval::Args),
+    /// Apply data parameters to a compiled script
+    Apply(apply::Args),
+    /// Print the cost model of a Plutus version as JSON
+    CostModel(cost_model::Args),
+    /// Step through the evaluation of an Untyped Plutus Core program
+    Debug(debug::Args),
+    /// Profile the budget an Untyped Plutus Core program spends per function
+    Profile(profile::Args),
 }
 
 impl Default for Cmd {
@@ -19,6 +41,150 @@ impl Cmd {
     pub fn exec(self) -> miette::Result<()> {
         match self {
             Cmd::Eval(args) => args.exec(),
+            Cmd::Apply(args) => args.exec(),
+            Cmd::CostModel(args) => args.exec(),
+            Cmd::Debug(args) => args.exec(),
+            Cmd::Profile(args) => args.exec(),
+        }
+    }
+}
+
+fn parse_plutus_version(s: &str) -> Result<PlutusVersion, String> {
+    match s.to_lowercase().as_str() {
+        "v1" => Ok(PlutusVersion::V1),
+        "v2" => Ok(PlutusVersion::V2),
+        "v3" => Ok(PlutusVersion::V3),
+        _ => Err(format!(
+            "Unknown Plutus version: '{s}'. Valid options: v1, v2, v3"
+        )),
+    }
+}
+
+/// How a program is read, shared by the commands that evaluate one.
+#[derive(clap::Args)]
+struct Input {
+    /// Read the program from this file instead of stdin
+    #[clap(short, long)]
+    file: Option<String>,
+    #[clap(long)]
+    flat: bool,
+    /// Flat wrapped in one or two CBOR bytestrings
+    #[clap(long, conflicts_with = "flat")]
+    cbor: bool,
+    /// Hex encoded flat, possibly CBOR wrapped
+    #[clap(long, conflicts_with_all = ["flat", "cbor"])]
+    hex: bool,
+    #[clap(short = 'A', long)]
+    args: Vec<String>,
+}
+
+impl Input {
+    fn read(&self) -> miette::Result<Vec<u8>> {
+        if let Some(file_path) = &self.file {
+            return std::fs::read(file_path).into_diagnostic();
         }
+
+        let mut buffer = Vec::new();
+
+        io::stdin().read_to_end(&mut buffer).into_diagnostic()?;
+
+        Ok(buffer)
+    }
+
+    /// Reads and decodes or parses the program, then applies the arguments to it.
+    fn program<'a>(&self, arena: &'a Bump) -> miette::Result<&'a Program<'a, DeBruijn>> {
+        let program = self.read()?;
+
+        let program = if self.flat {
+            uplc_turbo::flat::decode(arena, &program).into_diagnostic()?
+        } else if self.cbor {
+            uplc_turbo::flat::decode_cbor(arena, &program).into_diagnostic()?
+        } else if self.hex {
+            let hex = String::from_utf8(program).into_diagnostic()?;
+
+            uplc_turbo::flat::decode_hex(arena, &hex).into_diagnostic()?
+        } else {
+            let program_string = arena.alloc_str(&String::from_utf8(program).into_diagnostic()?);
+
+            let parse_result =
+                uplc_turbo::syn::parse_program_strict(arena, program_string).into_result();
+
+            match parse_result {
+                Ok(program) => program,
+                Err(errs) => {
+                    let errs = errs
+                        .into_iter()
+                        .map(|e| format!("{e}"))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+
+                    miette::bail!("failed to parse program\n{}", errs);
+                }
+            }
+        };
+
+        Ok(self
+            .args(arena)?
+            .into_iter()
+            .fold(program, |program, arg| program.apply(arena, arg)))
+    }
+
+    /// Whether the program is decoded rather than parsed, and so has no names.
+    fn is_encoded(&self) -> bool {
+        self.flat || self.cbor || self.hex
+    }
+
+    /// Like [`Input::program`] for a textual program, keeping its variable names.
+    fn named_program<'a>(
+        &self,
+        arena: &'a Bump,
+    ) -> miette::Result<&'a Program<'a, NamedDeBruijn<'a>>> {
+        let program = self.read()?;
+
+        let program_string = arena.alloc_str(&String::from_utf8(program).into_diagnostic()?);
+
+        let program =
+            match uplc_turbo::syn::parse_named_program(arena, program_string).into_result() {
+                Ok(program) => program.to_named_debruijn(arena).into_diagnostic()?,
+                Err(errs) => {
+                    let errs = errs
+                        .into_iter()
+                        .map(|e| format!("{e}"))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+
+                    miette::bail!("failed to parse program\n{}", errs);
+                }
+            };
+
+        Ok(self.args(arena)?.into_iter().fold(program, |program, arg| {
+            program.apply(arena, arg.to_named_debruijn(arena))
+        }))
+    }
+
+    fn args<'a>(&self, arena: &'a Bump) -> miette::Result<Vec<&'a Term<'a, DeBruijn>>> {
+        let mut parsed_args = vec![];
+
+        for (index, arg) in self.args.iter().enumerate() {
+            let parse_result =
+                uplc_turbo::syn::parse_term_strict(arena, arena.alloc_str(arg)).into_result();
+
+            let term = match parse_result {
+                Ok(term) => term,
+                Err(errs) => {
+                    let errs = errs
+                        .into_iter()
+                        .map(|e| format!("{e}"))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+
+                    miette::bail!("failed to parse argument {}: {}\n{}", index + 1, arg, errs);
+                }
+            };
+
+            parsed_args.push(term);
+        }
+
+        Ok(parsed_args)
     }
 }
@@ -0,0 +1,58 @@
+use miette::IntoDiagnostic;
+use uplc_turbo::{bumpalo::Bump, program::EvalOptions};
+
+use super::{parse_plutus_version, Input};
+
+#[derive(clap::Args)]
+pub struct Args {
+    #[clap(flatten)]
+    input: Input,
+    #[clap(short = 'v', long)]
+    plutus_version: Option<String>,
+    /// Write the stacks in folded format here, for flamegraph tools
+    #[clap(long)]
+    folded: String,
+    /// Weigh the stacks by memory instead of cpu
+    #[clap(long)]
+    mem: bool,
+}
+
+impl Args {
+    pub fn exec(self) -> miette::Result<()> {
+        let arena = Bump::with_capacity(1_024_000);
+
+        let options = match self.plutus_version {
+            Some(version_str) => EvalOptions::for_version(
+                parse_plutus_version(&version_str).map_err(|e| miette::miette!("{}", e))?,
+            ),
+            None => EvalOptions::default(),
+        };
+
+        // decoded programs have no names, profiling them directly numbers
+        // their lambdas rather than naming them all `i`
+        let (eval_result, profile) = if self.input.is_encoded() {
+            self.input.program(&arena)?.profile(&arena, options)
+        } else {
+            self.input.named_program(&arena)?.profile(&arena, options)
+        };
+
+        let folded = if self.mem {
+            profile.folded_mem()
+        } else {
+            profile.folded_cpu()
+        };
+
+        std::fs::write(&self.folded, folded).into_diagnostic()?;
+
+        match eval_result.term {
+            Ok(term) => println!("{term}"),
+            Err(err) => println!("error: {err}"),
+        }
+
+        println!();
+        println!("cpu: {}", eval_result.info.consumed_budget.cpu);
+        println!("mem: {}", eval_result.info.consumed_budget.mem);
+
+        Ok(())
+    }
+}
@@ -6,4 +6,5 @@ criterion_main! {
     benchmarks::haskell,
     benchmarks::add_integer,
     benchmarks::fibonacci,
+    benchmarks::deep_env,
 }
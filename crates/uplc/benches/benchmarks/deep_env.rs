@@ -0,0 +1,29 @@
+use bumpalo::Bump;
+use criterion::{criterion_group, Criterion};
+
+use uplc_turbo::{binder::DeBruijn, term::Term};
+
+use super::utils;
+
+/// Binds `depth` variables one inside the other and looks up the outermost,
+/// so the environment is pushed to `depth` times while it keeps growing.
+pub fn run(c: &mut Criterion) {
+    for depth in [100, 1_000, 10_000] {
+        c.bench_function(&format!("deep_env_{depth}"), |b| {
+            b.iter_with_setup(
+                || {
+                    utils::setup_term(|arena: &Bump| {
+                        (0..depth).fold(Term::var(arena, DeBruijn::new(arena, depth)), |body, i| {
+                            body.lambda(arena, DeBruijn::zero(arena))
+                                .apply(arena, Term::integer_from(arena, i as i128))
+                        })
+                    })
+                },
+                // Benchmark: only the eval call
+                |state| state.exec(),
+            )
+        });
+    }
+}
+
+criterion_group!(deep_env, run);
@@ -1,8 +1,10 @@
 mod add_integer;
+mod deep_env;
 mod fibonacci;
 mod haskell;
 mod utils;
 
 pub use add_integer::add_integer;
+pub use deep_env::deep_env;
 pub use fibonacci::fibonacci;
 pub use haskell::haskell;
@@ -0,0 +1,495 @@
+use std::collections::HashSet;
+
+use bumpalo::{collections::Vec as BumpVec, Bump};
+
+use crate::{program::Program, term::Term};
+
+use super::{DeBruijn, Eval, Name, NamedDeBruijn};
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ConvertError {
+    #[error("Free unique: {text} {unique}")]
+    FreeUnique { text: String, unique: usize },
+    #[error("Free index: {0}")]
+    FreeIndex(usize),
+}
+
+/// Maps the binders of a term one lambda at a time.
+///
+/// `enter` is called for every lambda parameter before its body is converted
+/// and `exit` right after, so implementations can keep a scope stack.
+trait Converter<'a, A, B> {
+    fn enter(&mut self, arena: &'a Bump, parameter: &A) -> Result<&'a B, ConvertError>;
+
+    fn exit(&mut self);
+
+    fn var(&mut self, arena: &'a Bump, var: &A) -> Result<&'a B, ConvertError>;
+}
+
+fn convert<'a, A, B>(
+    arena: &'a Bump,
+    term: &'a Term<'a, A>,
+    converter: &mut impl Converter<'a, A, B>,
+) -> Result<&'a Term<'a, B>, ConvertError> {
+    let converted = match term {
+        Term::Var(var) => Term::var(arena, converter.var(arena, var)?),
+        Term::Lambda { parameter, body } => {
+            let parameter = converter.enter(arena, parameter)?;
+
+            let body = convert(arena, body, converter);
+
+            converter.exit();
+
+            body?.lambda(arena, parameter)
+        }
+        Term::Apply { function, argument } => {
+            let function = convert(arena, function, converter)?;
+            let argument = convert(arena, argument, converter)?;
+
+            function.apply(arena, argument)
+        }
+        Term::Delay(body) => convert(arena, body, converter)?.delay(arena),
+        Term::Force(body) => convert(arena, body, converter)?.force(arena),
+        Term::Case { constr, branches } => {
+            let constr = convert(arena, constr, converter)?;
+            let branches = convert_all(arena, branches, converter)?;
+
+            Term::case(arena, constr, branches)
+        }
+        Term::Constr { tag, fields } => {
+            let fields = convert_all(arena, fields, converter)?;
+
+            Term::constr(arena, *tag, fields)
+        }
+        Term::Constant(constant) => Term::constant(arena, constant),
+        Term::Builtin(fun) => arena.alloc(Term::Builtin(fun)),
+        Term::Error => Term::error(arena),
+    };
+
+    Ok(converted)
+}
+
+fn convert_all<'a, A, B>(
+    arena: &'a Bump,
+    terms: &'a [&'a Term<'a, A>],
+    converter: &mut impl Converter<'a, A, B>,
+) -> Result<&'a [&'a Term<'a, B>], ConvertError> {
+    let mut converted = BumpVec::with_capacity_in(terms.len(), arena);
+
+    for term in terms {
+        converted.push(convert(arena, term, converter)?);
+    }
+
+    Ok(arena.alloc(converted))
+}
+
+// Uniques of the enclosing lambdas, innermost last.
+#[derive(Default)]
+struct NameToNamedDeBruijn {
+    scopes: Vec<usize>,
+}
+
+impl<'a> Converter<'a, Name<'a>, NamedDeBruijn<'a>> for NameToNamedDeBruijn {
+    fn enter(
+        &mut self,
+        arena: &'a Bump,
+        parameter: &Name<'a>,
+    ) -> Result<&'a NamedDeBruijn<'a>, ConvertError> {
+        self.scopes.push(parameter.unique());
+
+        Ok(NamedDeBruijn::new(arena, parameter.text(), 0))
+    }
+
+    fn exit(&mut self) {
+        self.scopes.pop();
+    }
+
+    fn var(
+        &mut self,
+        arena: &'a Bump,
+        var: &Name<'a>,
+    ) -> Result<&'a NamedDeBruijn<'a>, ConvertError> {
+        let position = self
+            .scopes
+            .iter()
+            .rposition(|unique| *unique == var.unique())
+            .ok_or_else(|| ConvertError::FreeUnique {
+                text: var.text().to_string(),
+                unique: var.unique(),
+            })?;
+
+        Ok(NamedDeBruijn::new(
+            arena,
+            var.text(),
+            self.scopes.len() - position,
+        ))
+    }
+}
+
+/// Turns indices back into names, inventing a fresh one for every lambda.
+///
+/// Names are unique across the whole term so the result still means the same
+/// thing once printed, even where the original binders shadowed each other.
+/// A [`NamedDeBruijn`] parameter's text is used as is when it hasn't been
+/// taken yet, otherwise names look like `i_0`, `i_1`, ...
+#[derive(Default)]
+struct IndexToName<'a> {
+    // binders of the enclosing lambdas, innermost last
+    scopes: Vec<&'a Name<'a>>,
+    taken: HashSet<&'a str>,
+    next_unique: usize,
+}
+
+impl<'a> IndexToName<'a> {
+    fn bind(&mut self, arena: &'a Bump, seed: Option<&'a str>) -> &'a Name<'a> {
+        let unique = self.next_unique;
+
+        self.next_unique += 1;
+
+        let text = match seed.filter(|seed| is_valid_name(seed) && !self.taken.contains(seed)) {
+            Some(seed) => seed,
+            None => {
+                let base = seed.filter(|seed| is_valid_name(seed)).unwrap_or("i");
+
+                let mut suffix = unique;
+
+                loop {
+                    let candidate = format!("{base}_{suffix}");
+
+                    if !self.taken.contains(candidate.as_str()) {
+                        break &*arena.alloc_str(&candidate);
+                    }
+
+                    suffix += 1;
+                }
+            }
+        };
+
+        self.taken.insert(text);
+
+        let name = Name::new(arena, text, unique);
+
+        self.scopes.push(name);
+
+        name
+    }
+
+    fn lookup(&self, index: usize) -> Result<&'a Name<'a>, ConvertError> {
+        if index == 0 || index > self.scopes.len() {
+            return Err(ConvertError::FreeIndex(index));
+        }
+
+        Ok(self.scopes[self.scopes.len() - index])
+    }
+}
+
+// Same grammar as the textual syntax, so invented names can be parsed back.
+fn is_valid_name(text: &str) -> bool {
+    let mut chars = text.chars();
+
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '\'')
+}
+
+impl<'a> Converter<'a, NamedDeBruijn<'a>, Name<'a>> for IndexToName<'a> {
+    fn enter(
+        &mut self,
+        arena: &'a Bump,
+        parameter: &NamedDeBruijn<'a>,
+    ) -> Result<&'a Name<'a>, ConvertError> {
+        Ok(self.bind(arena, Some(parameter.text())))
+    }
+
+    fn exit(&mut self) {
+        self.scopes.pop();
+    }
+
+    fn var(
+        &mut self,
+        _arena: &'a Bump,
+        var: &NamedDeBruijn<'a>,
+    ) -> Result<&'a Name<'a>, ConvertError> {
+        self.lookup(var.index())
+    }
+}
+
+impl<'a> Converter<'a, DeBruijn, Name<'a>> for IndexToName<'a> {
+    fn enter(
+        &mut self,
+        arena: &'a Bump,
+        _parameter: &DeBruijn,
+    ) -> Result<&'a Name<'a>, ConvertError> {
+        Ok(self.bind(arena, None))
+    }
+
+    fn exit(&mut self) {
+        self.scopes.pop();
+    }
+
+    fn var(&mut self, _arena: &'a Bump, var: &DeBruijn) -> Result<&'a Name<'a>, ConvertError> {
+        self.lookup(var.index())
+    }
+}
+
+struct NamedDeBruijnToDeBruijn;
+
+impl<'a> Converter<'a, NamedDeBruijn<'a>, DeBruijn> for NamedDeBruijnToDeBruijn {
+    fn enter(
+        &mut self,
+        arena: &'a Bump,
+        _parameter: &NamedDeBruijn<'a>,
+    ) -> Result<&'a DeBruijn, ConvertError> {
+        Ok(DeBruijn::zero(arena))
+    }
+
+    fn exit(&mut self) {}
+
+    fn var(
+        &mut self,
+        arena: &'a Bump,
+        var: &NamedDeBruijn<'a>,
+    ) -> Result<&'a DeBruijn, ConvertError> {
+        Ok(DeBruijn::new(arena, var.index()))
+    }
+}
+
+struct DeBruijnToNamedDeBruijn;
+
+impl<'a> Converter<'a, DeBruijn, NamedDeBruijn<'a>> for DeBruijnToNamedDeBruijn {
+    fn enter(
+        &mut self,
+        arena: &'a Bump,
+        _parameter: &DeBruijn,
+    ) -> Result<&'a NamedDeBruijn<'a>, ConvertError> {
+        Ok(NamedDeBruijn::new(arena, "i", 0))
+    }
+
+    fn exit(&mut self) {}
+
+    fn var(
+        &mut self,
+        arena: &'a Bump,
+        var: &DeBruijn,
+    ) -> Result<&'a NamedDeBruijn<'a>, ConvertError> {
+        Ok(NamedDeBruijn::new(arena, "i", var.index()))
+    }
+}
+
+// infallible conversions never produce an error
+fn infallible<T>(result: Result<T, ConvertError>) -> T {
+    result.unwrap_or_else(|e| unreachable!("{e}"))
+}
+
+impl<'a> Term<'a, Name<'a>> {
+    pub fn to_named_debruijn(
+        &'a self,
+        arena: &'a Bump,
+    ) -> Result<&'a Term<'a, NamedDeBruijn<'a>>, ConvertError> {
+        convert(arena, self, &mut NameToNamedDeBruijn::default())
+    }
+
+    pub fn to_debruijn(&'a self, arena: &'a Bump) -> Result<&'a Term<'a, DeBruijn>, ConvertError> {
+        Ok(self.to_named_debruijn(arena)?.to_debruijn(arena))
+    }
+}
+
+impl<'a> Term<'a, NamedDeBruijn<'a>> {
+    pub fn to_debruijn(&'a self, arena: &'a Bump) -> &'a Term<'a, DeBruijn> {
+        infallible(convert(arena, self, &mut NamedDeBruijnToDeBruijn))
+    }
+
+    pub fn to_name(&'a self, arena: &'a Bump) -> Result<&'a Term<'a, Name<'a>>, ConvertError> {
+        convert(arena, self, &mut IndexToName::default())
+    }
+}
+
+impl<'a> Term<'a, DeBruijn> {
+    pub fn to_named_debruijn(&'a self, arena: &'a Bump) -> &'a Term<'a, NamedDeBruijn<'a>> {
+        infallible(convert(arena, self, &mut DeBruijnToNamedDeBruijn))
+    }
+
+    pub fn to_name(&'a self, arena: &'a Bump) -> Result<&'a Term<'a, Name<'a>>, ConvertError> {
+        convert(arena, self, &mut IndexToName::default())
+    }
+}
+
+impl<'a> Program<'a, Name<'a>> {
+    pub fn to_named_debruijn(
+        &'a self,
+        arena: &'a Bump,
+    ) -> Result<&'a Program<'a, NamedDeBruijn<'a>>, ConvertError> {
+        let term = self.term.to_named_debruijn(arena)?;
+
+        Ok(Program::new(arena, self.version, term))
+    }
+
+    pub fn to_debruijn(
+        &'a self,
+        arena: &'a Bump,
+    ) -> Result<&'a Program<'a, DeBruijn>, ConvertError> {
+        let term = self.term.to_debruijn(arena)?;
+
+        Ok(Program::new(arena, self.version, term))
+    }
+}
+
+impl<'a> Program<'a, NamedDeBruijn<'a>> {
+    pub fn to_debruijn(&'a self, arena: &'a Bump) -> &'a Program<'a, DeBruijn> {
+        Program::new(arena, self.version, self.term.to_debruijn(arena))
+    }
+
+    pub fn to_name(&'a self, arena: &'a Bump) -> Result<&'a Program<'a, Name<'a>>, ConvertError> {
+        let term = self.term.to_name(arena)?;
+
+        Ok(Program::new(arena, self.version, term))
+    }
+}
+
+impl<'a> Program<'a, DeBruijn> {
+    pub fn to_named_debruijn(&'a self, arena: &'a Bump) -> &'a Program<'a, NamedDeBruijn<'a>> {
+        Program::new(arena, self.version, self.term.to_named_debruijn(arena))
+    }
+
+    pub fn to_name(&'a self, arena: &'a Bump) -> Result<&'a Program<'a, Name<'a>>, ConvertError> {
+        let term = self.term.to_name(arena)?;
+
+        Ok(Program::new(arena, self.version, term))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bumpalo::Bump;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        binder::{DeBruijn, Name, NamedDeBruijn},
+        syn,
+        term::Term,
+    };
+
+    use super::ConvertError;
+
+    #[test]
+    fn named_program_evaluates_like_debruijn() {
+        let arena = Bump::new();
+
+        let source = "(program 1.1.0 [(lam x (lam y [(builtin subtractInteger) x y])) (con integer 5) (con integer 3)])";
+
+        let named = syn::parse_named_program(&arena, source)
+            .into_result()
+            .unwrap();
+        let expected = syn::parse_program(&arena, source).into_result().unwrap();
+
+        let program = named.to_debruijn(&arena).unwrap();
+
+        assert_eq!(program, expected);
+
+        let result = program.eval(&arena);
+
+        assert_eq!(result.term.unwrap(), Term::integer_from(&arena, 2));
+    }
+
+    #[test]
+    fn shadowed_names_get_distinct_uniques() {
+        let arena = Bump::new();
+
+        let term = syn::parse_named_term(&arena, "(lam x (lam x x))")
+            .into_result()
+            .unwrap();
+
+        let Term::Lambda {
+            parameter: outer,
+            body,
+        } = term
+        else {
+            panic!("expected lambda");
+        };
+
+        let Term::Lambda {
+            parameter: inner,
+            body,
+        } = body
+        else {
+            panic!("expected lambda");
+        };
+
+        assert_ne!(outer.unique(), inner.unique());
+        assert_eq!(*body, &Term::Var(*inner));
+    }
+
+    #[test]
+    fn debruijn_round_trips_through_name() {
+        let arena = Bump::new();
+
+        let term = syn::parse_term(&arena, "(lam a (lam b [b (delay a) (lam c [c a])]))")
+            .into_result()
+            .unwrap();
+
+        let named = term.to_name(&arena).unwrap();
+
+        assert_eq!(named.to_debruijn(&arena).unwrap(), term);
+    }
+
+    #[test]
+    fn invents_names_for_debruijn() {
+        let arena = Bump::new();
+
+        let term = syn::parse_term(&arena, "(lam a (lam b [b (lam c [c a])]))")
+            .into_result()
+            .unwrap();
+
+        let named = term.to_name(&arena).unwrap();
+
+        assert_eq!(
+            named.to_pretty(),
+            "(lam i_0 (lam i_1 [i_1 (lam i_2 [i_2 i_0])]))"
+        );
+    }
+
+    #[test]
+    fn seeds_names_from_named_debruijn_text() {
+        let arena = Bump::new();
+
+        // x is shadowed, the inner one refers to both so they need distinct names
+        let term = Term::var(&arena, NamedDeBruijn::new(&arena, "x", 3))
+            .apply(
+                &arena,
+                Term::var(&arena, NamedDeBruijn::new(&arena, "x", 1)),
+            )
+            .lambda(&arena, NamedDeBruijn::new(&arena, "x", 0))
+            .lambda(&arena, NamedDeBruijn::new(&arena, "y", 0))
+            .lambda(&arena, NamedDeBruijn::new(&arena, "x", 0));
+
+        let named = term.to_name(&arena).unwrap();
+
+        let printed = named.to_pretty();
+
+        assert_eq!(printed, "(lam x (lam y (lam x_2 [x x_2])))");
+
+        let reparsed = syn::parse_term(&arena, &printed).into_result().unwrap();
+
+        assert_eq!(reparsed, term.to_debruijn(&arena));
+    }
+
+    #[test]
+    fn free_variables_are_reported() {
+        let arena = Bump::new();
+
+        let term =
+            Term::var(&arena, Name::new(&arena, "x", 7)).lambda(&arena, Name::new(&arena, "y", 1));
+
+        assert_eq!(
+            term.to_debruijn(&arena),
+            Err(ConvertError::FreeUnique {
+                text: "x".to_string(),
+                unique: 7
+            })
+        );
+
+        let term =
+            Term::var(&arena, DeBruijn::new(&arena, 2)).lambda(&arena, DeBruijn::zero(&arena));
+
+        assert_eq!(term.to_name(&arena), Err(ConvertError::FreeIndex(2)));
+    }
+}
@@ -1,9 +1,11 @@
 use bumpalo::Bump;
 
+mod convert;
 mod debruijn;
 mod name;
 mod named_debruijn;
 
+pub use convert::ConvertError;
 pub use debruijn::*;
 pub use name::*;
 pub use named_debruijn::*;
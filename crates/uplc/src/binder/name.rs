@@ -2,7 +2,7 @@ use bumpalo::Bump;
 
 use super::Binder;
 
-#[derive(Debug)]
+#[derive(Debug, Eq, PartialEq)]
 pub struct Name<'a> {
     text: &'a str,
     unique: usize,
@@ -12,6 +12,14 @@ impl<'a> Name<'a> {
     pub fn new(arena: &'a Bump, text: &'a str, unique: usize) -> &'a Self {
         arena.alloc(Name { text, unique })
     }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn unique(&self) -> usize {
+        self.unique
+    }
 }
 
 impl<'a> Binder<'a> for Name<'a> {
@@ -2,7 +2,7 @@ use bumpalo::Bump;
 
 use super::{Binder, Eval};
 
-#[derive(Debug)]
+#[derive(Debug, Eq, PartialEq)]
 pub struct NamedDeBruijn<'a> {
     text: &'a str,
     index: usize,
@@ -12,6 +12,10 @@ impl<'a> NamedDeBruijn<'a> {
     pub fn new(arena: &'a Bump, text: &'a str, index: usize) -> &'a Self {
         arena.alloc(NamedDeBruijn { text, index })
     }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
 }
 
 impl<'a> Binder<'a> for NamedDeBruijn<'a> {
@@ -31,15 +31,29 @@ pub trait Compressable {
         Self: std::marker::Sized;
 }
 
-impl Compressable for blst::blst_p1 {
-    fn compress<'a>(&self, arena: &'a Bump) -> &'a [u8] {
-        let mut out = [0u8; BLST_P1_COMPRESSED_SIZE];
+pub fn g1_compressed(point: &blst::blst_p1) -> [u8; BLST_P1_COMPRESSED_SIZE] {
+    let mut out = [0; BLST_P1_COMPRESSED_SIZE];
 
-        unsafe {
-            blst::blst_p1_compress(&mut out as *mut _, self);
-        };
+    unsafe {
+        blst::blst_p1_compress(&mut out as *mut _, point);
+    };
+
+    out
+}
+
+pub fn g2_compressed(point: &blst::blst_p2) -> [u8; BLST_P2_COMPRESSED_SIZE] {
+    let mut out = [0; BLST_P2_COMPRESSED_SIZE];
+
+    unsafe {
+        blst::blst_p2_compress(&mut out as *mut _, point);
+    };
 
-        arena.alloc(BumpVec::from_iter_in(out, arena))
+    out
+}
+
+impl Compressable for blst::blst_p1 {
+    fn compress<'a>(&self, arena: &'a Bump) -> &'a [u8] {
+        arena.alloc(BumpVec::from_iter_in(g1_compressed(self), arena))
     }
 
     fn uncompress<'a>(arena: &'a Bump, bytes: &[u8]) -> Result<&'a Self, BlsError> {
@@ -73,13 +87,7 @@ impl Compressable for blst::blst_p1 {
 
 impl Compressable for blst::blst_p2 {
     fn compress<'a>(&self, arena: &'a Bump) -> &'a [u8] {
-        let mut out = [0; BLST_P2_COMPRESSED_SIZE];
-
-        unsafe {
-            blst::blst_p2_compress(&mut out as *mut _, self);
-        };
-
-        arena.alloc(BumpVec::from_iter_in(out, arena))
+        arena.alloc(BumpVec::from_iter_in(g2_compressed(self), arena))
     }
 
     fn uncompress<'a>(arena: &'a Bump, bytes: &[u8]) -> Result<&'a Self, BlsError> {
@@ -0,0 +1,292 @@
+use crate::{
+    binder::Eval,
+    machine::{PlutusVersion, ProtocolVersion},
+    program::Program,
+    term::Term,
+};
+
+use super::DefaultFunction;
+
+/// A builtin the ledger doesn't enable for a script's Plutus version at the
+/// given protocol version.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("builtin {fun} is not available in Plutus {plutus_version:?} at protocol version {protocol_version}")]
+pub struct UnavailableBuiltin {
+    pub fun: DefaultFunction,
+    pub plutus_version: PlutusVersion,
+    pub protocol_version: ProtocolVersion,
+}
+
+impl DefaultFunction {
+    /// The protocol version from which scripts of `plutus_version` may use
+    /// this builtin. From [`ProtocolVersion::PV11`] every Plutus version may
+    /// use every builtin.
+    pub fn enabled_since(&self, plutus_version: PlutusVersion) -> ProtocolVersion {
+        use DefaultFunction::*;
+
+        let (v1, v2, v3) = match self {
+            AddInteger
+            | SubtractInteger
+            | MultiplyInteger
+            | DivideInteger
+            | QuotientInteger
+            | RemainderInteger
+            | ModInteger
+            | EqualsInteger
+            | LessThanInteger
+            | LessThanEqualsInteger
+            | AppendByteString
+            | ConsByteString
+            | SliceByteString
+            | LengthOfByteString
+            | IndexByteString
+            | EqualsByteString
+            | LessThanByteString
+            | LessThanEqualsByteString
+            | Sha2_256
+            | Sha3_256
+            | Blake2b_256
+            | VerifyEd25519Signature
+            | AppendString
+            | EqualsString
+            | EncodeUtf8
+            | DecodeUtf8
+            | IfThenElse
+            | ChooseUnit
+            | Trace
+            | FstPair
+            | SndPair
+            | ChooseList
+            | MkCons
+            | HeadList
+            | TailList
+            | NullList
+            | ChooseData
+            | ConstrData
+            | MapData
+            | ListData
+            | IData
+            | BData
+            | UnConstrData
+            | UnMapData
+            | UnListData
+            | UnIData
+            | UnBData
+            | EqualsData
+            | MkPairData
+            | MkNilData
+            | MkNilPairData => (
+                ProtocolVersion::ALONZO,
+                ProtocolVersion::VASIL,
+                ProtocolVersion::CHANG,
+            ),
+            SerialiseData => (
+                ProtocolVersion::PV11,
+                ProtocolVersion::VASIL,
+                ProtocolVersion::CHANG,
+            ),
+            VerifyEcdsaSecp256k1Signature | VerifySchnorrSecp256k1Signature => (
+                ProtocolVersion::PV11,
+                ProtocolVersion::VALENTINE,
+                ProtocolVersion::CHANG,
+            ),
+            IntegerToByteString | ByteStringToInteger => (
+                ProtocolVersion::PV11,
+                ProtocolVersion::PLOMIN,
+                ProtocolVersion::CHANG,
+            ),
+            Bls12_381_G1_Add
+            | Bls12_381_G1_Neg
+            | Bls12_381_G1_ScalarMul
+            | Bls12_381_G1_Equal
+            | Bls12_381_G1_Compress
+            | Bls12_381_G1_Uncompress
+            | Bls12_381_G1_HashToGroup
+            | Bls12_381_G2_Add
+            | Bls12_381_G2_Neg
+            | Bls12_381_G2_ScalarMul
+            | Bls12_381_G2_Equal
+            | Bls12_381_G2_Compress
+            | Bls12_381_G2_Uncompress
+            | Bls12_381_G2_HashToGroup
+            | Bls12_381_MillerLoop
+            | Bls12_381_MulMlResult
+            | Bls12_381_FinalVerify
+            | Keccak_256
+            | Blake2b_224 => (
+                ProtocolVersion::PV11,
+                ProtocolVersion::PV11,
+                ProtocolVersion::CHANG,
+            ),
+            AndByteString | OrByteString | XorByteString | ComplementByteString | ReadBit
+            | WriteBits | ReplicateByte | ShiftByteString | RotateByteString | CountSetBits
+            | FindFirstSetBit | Ripemd_160 => (
+                ProtocolVersion::PV11,
+                ProtocolVersion::PV11,
+                ProtocolVersion::PLOMIN,
+            ),
+            ExpModInteger | DropList | LengthOfArray | ListToArray | IndexArray => (
+                ProtocolVersion::PV11,
+                ProtocolVersion::PV11,
+                ProtocolVersion::PV11,
+            ),
+        };
+
+        match plutus_version {
+            PlutusVersion::V1 => v1,
+            PlutusVersion::V2 => v2,
+            PlutusVersion::V3 => v3,
+        }
+    }
+
+    pub fn is_available(
+        &self,
+        plutus_version: PlutusVersion,
+        protocol_version: ProtocolVersion,
+    ) -> bool {
+        self.enabled_since(plutus_version) <= protocol_version
+    }
+
+    pub fn check_available(
+        &self,
+        plutus_version: PlutusVersion,
+        protocol_version: ProtocolVersion,
+    ) -> Result<(), UnavailableBuiltin> {
+        if self.is_available(plutus_version, protocol_version) {
+            Ok(())
+        } else {
+            Err(UnavailableBuiltin {
+                fun: *self,
+                plutus_version,
+                protocol_version,
+            })
+        }
+    }
+}
+
+impl<'a, V> Program<'a, V>
+where
+    V: Eval<'a>,
+{
+    /// Finds the first builtin the ledger would reject for this script,
+    /// without evaluating it.
+    pub fn check_builtins(
+        &self,
+        plutus_version: PlutusVersion,
+        protocol_version: ProtocolVersion,
+    ) -> Result<(), UnavailableBuiltin> {
+        self.term.check_builtins(plutus_version, protocol_version)
+    }
+}
+
+impl<'a, V> Term<'a, V>
+where
+    V: Eval<'a>,
+{
+    pub fn check_builtins(
+        &self,
+        plutus_version: PlutusVersion,
+        protocol_version: ProtocolVersion,
+    ) -> Result<(), UnavailableBuiltin> {
+        let check = |term: &Term<'a, V>| term.check_builtins(plutus_version, protocol_version);
+
+        match self {
+            Term::Builtin(fun) => fun.check_available(plutus_version, protocol_version),
+            Term::Lambda { body, .. } => check(body),
+            Term::Delay(body) | Term::Force(body) => check(body),
+            Term::Apply { function, argument } => {
+                check(function)?;
+                check(argument)
+            }
+            Term::Case { constr, branches } => {
+                check(constr)?;
+                branches.iter().try_for_each(|branch| check(branch))
+            }
+            Term::Constr { fields, .. } => fields.iter().try_for_each(|field| check(field)),
+            Term::Var(_) | Term::Constant(_) | Term::Error => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bumpalo::Bump;
+
+    use crate::{
+        binder::DeBruijn,
+        builtin::DefaultFunction,
+        machine::{MachineError, PlutusVersion, ProtocolVersion},
+        program::{EvalOptions, Program},
+        syn,
+    };
+
+    use super::UnavailableBuiltin;
+
+    #[test]
+    fn availability_by_version() {
+        let serialise = DefaultFunction::SerialiseData;
+
+        assert!(!serialise.is_available(PlutusVersion::V1, ProtocolVersion::PLOMIN));
+        assert!(!serialise.is_available(PlutusVersion::V2, ProtocolVersion::ALONZO));
+        assert!(serialise.is_available(PlutusVersion::V2, ProtocolVersion::VASIL));
+
+        let and = DefaultFunction::AndByteString;
+
+        assert!(!and.is_available(PlutusVersion::V3, ProtocolVersion::CHANG));
+        assert!(and.is_available(PlutusVersion::V3, ProtocolVersion::PLOMIN));
+    }
+
+    #[test]
+    fn every_builtin_is_available_from_pv11() {
+        let drop_list = DefaultFunction::DropList;
+
+        assert!(!drop_list.is_available(PlutusVersion::V1, ProtocolVersion::PLOMIN));
+        assert!(drop_list.is_available(PlutusVersion::V1, ProtocolVersion::PV11));
+
+        for version in [PlutusVersion::V1, PlutusVersion::V2, PlutusVersion::V3] {
+            assert!(DefaultFunction::Bls12_381_G1_Neg.is_available(version, ProtocolVersion::PV11));
+            assert!(DefaultFunction::Ripemd_160.is_available(version, ProtocolVersion::PV11));
+            assert!(DefaultFunction::SerialiseData.is_available(version, ProtocolVersion::PV11));
+        }
+    }
+
+    #[test]
+    fn unavailable_builtin_fails() {
+        let arena = Bump::new();
+
+        let program: &Program<DeBruijn> = syn::parse_program(
+            &arena,
+            "(program 1.0.0 [(builtin addInteger) (con integer 1) [(builtin bls12_381_G1_neg) (con bls12_381_G1_element 0xc00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000)]])",
+        )
+        .into_result()
+        .unwrap();
+
+        let expected = UnavailableBuiltin {
+            fun: DefaultFunction::Bls12_381_G1_Neg,
+            plutus_version: PlutusVersion::V2,
+            protocol_version: ProtocolVersion::PLOMIN,
+        };
+
+        assert_eq!(
+            program.check_builtins(PlutusVersion::V2, ProtocolVersion::PLOMIN),
+            Err(expected)
+        );
+        assert_eq!(
+            program.check_builtins(PlutusVersion::V3, ProtocolVersion::PLOMIN),
+            Ok(())
+        );
+
+        let result = program.eval_with(
+            &arena,
+            EvalOptions {
+                protocol_version: ProtocolVersion::PLOMIN,
+                ..EvalOptions::for_version(PlutusVersion::V2)
+            },
+        );
+
+        assert!(matches!(
+            result.term,
+            Err(MachineError::UnavailableBuiltin(err)) if err == expected
+        ));
+    }
+}
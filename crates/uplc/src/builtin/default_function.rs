@@ -1,6 +1,8 @@
+use std::fmt;
+
 #[repr(u8)]
 #[allow(non_camel_case_types)]
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum DefaultFunction {
     // Integer functions
     AddInteger = 0,
@@ -314,3 +316,104 @@ impl DefaultFunction {
         }
     }
 }
+
+impl fmt::Display for DefaultFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DefaultFunction::AddInteger => "addInteger",
+            DefaultFunction::SubtractInteger => "subtractInteger",
+            DefaultFunction::EqualsInteger => "equalsInteger",
+            DefaultFunction::LessThanEqualsInteger => "lessThanEqualsInteger",
+            DefaultFunction::MultiplyInteger => "multiplyInteger",
+            DefaultFunction::DivideInteger => "divideInteger",
+            DefaultFunction::QuotientInteger => "quotientInteger",
+            DefaultFunction::RemainderInteger => "remainderInteger",
+            DefaultFunction::ModInteger => "modInteger",
+            DefaultFunction::LessThanInteger => "lessThanInteger",
+            DefaultFunction::IfThenElse => "ifThenElse",
+            DefaultFunction::AppendByteString => "appendByteString",
+            DefaultFunction::EqualsByteString => "equalsByteString",
+            DefaultFunction::ConsByteString => "consByteString",
+            DefaultFunction::SliceByteString => "sliceByteString",
+            DefaultFunction::LengthOfByteString => "lengthOfByteString",
+            DefaultFunction::IndexByteString => "indexByteString",
+            DefaultFunction::LessThanByteString => "lessThanByteString",
+            DefaultFunction::LessThanEqualsByteString => "lessThanEqualsByteString",
+            DefaultFunction::Sha2_256 => "sha2_256",
+            DefaultFunction::Sha3_256 => "sha3_256",
+            DefaultFunction::Blake2b_256 => "blake2b_256",
+            DefaultFunction::Keccak_256 => "keccak_256",
+            DefaultFunction::Blake2b_224 => "blake2b_224",
+            DefaultFunction::VerifyEd25519Signature => "verifyEd25519Signature",
+            DefaultFunction::VerifyEcdsaSecp256k1Signature => "verifyEcdsaSecp256k1Signature",
+            DefaultFunction::VerifySchnorrSecp256k1Signature => "verifySchnorrSecp256k1Signature",
+            DefaultFunction::AppendString => "appendString",
+            DefaultFunction::EqualsString => "equalsString",
+            DefaultFunction::EncodeUtf8 => "encodeUtf8",
+            DefaultFunction::DecodeUtf8 => "decodeUtf8",
+            DefaultFunction::ChooseUnit => "chooseUnit",
+            DefaultFunction::Trace => "trace",
+            DefaultFunction::FstPair => "fstPair",
+            DefaultFunction::SndPair => "sndPair",
+            DefaultFunction::ChooseList => "chooseList",
+            DefaultFunction::MkCons => "mkCons",
+            DefaultFunction::HeadList => "headList",
+            DefaultFunction::TailList => "tailList",
+            DefaultFunction::NullList => "nullList",
+            DefaultFunction::ChooseData => "chooseData",
+            DefaultFunction::ConstrData => "constrData",
+            DefaultFunction::MapData => "mapData",
+            DefaultFunction::ListData => "listData",
+            DefaultFunction::IData => "iData",
+            DefaultFunction::BData => "bData",
+            DefaultFunction::UnConstrData => "unConstrData",
+            DefaultFunction::UnMapData => "unMapData",
+            DefaultFunction::UnListData => "unListData",
+            DefaultFunction::UnIData => "unIData",
+            DefaultFunction::UnBData => "unBData",
+            DefaultFunction::EqualsData => "equalsData",
+            DefaultFunction::SerialiseData => "serialiseData",
+            DefaultFunction::MkPairData => "mkPairData",
+            DefaultFunction::MkNilData => "mkNilData",
+            DefaultFunction::MkNilPairData => "mkNilPairData",
+            DefaultFunction::Bls12_381_G1_Add => "bls12_381_G1_add",
+            DefaultFunction::Bls12_381_G1_Neg => "bls12_381_G1_neg",
+            DefaultFunction::Bls12_381_G1_ScalarMul => "bls12_381_G1_scalarMul",
+            DefaultFunction::Bls12_381_G1_Equal => "bls12_381_G1_equal",
+            DefaultFunction::Bls12_381_G1_Compress => "bls12_381_G1_compress",
+            DefaultFunction::Bls12_381_G1_Uncompress => "bls12_381_G1_uncompress",
+            DefaultFunction::Bls12_381_G1_HashToGroup => "bls12_381_G1_hashToGroup",
+            DefaultFunction::Bls12_381_G2_Add => "bls12_381_G2_add",
+            DefaultFunction::Bls12_381_G2_Neg => "bls12_381_G2_neg",
+            DefaultFunction::Bls12_381_G2_ScalarMul => "bls12_381_G2_scalarMul",
+            DefaultFunction::Bls12_381_G2_Equal => "bls12_381_G2_equal",
+            DefaultFunction::Bls12_381_G2_Compress => "bls12_381_G2_compress",
+            DefaultFunction::Bls12_381_G2_Uncompress => "bls12_381_G2_uncompress",
+            DefaultFunction::Bls12_381_G2_HashToGroup => "bls12_381_G2_hashToGroup",
+            DefaultFunction::Bls12_381_MillerLoop => "bls12_381_millerLoop",
+            DefaultFunction::Bls12_381_MulMlResult => "bls12_381_mulMlResult",
+            DefaultFunction::Bls12_381_FinalVerify => "bls12_381_finalVerify",
+            DefaultFunction::IntegerToByteString => "integerToByteString",
+            DefaultFunction::ByteStringToInteger => "byteStringToInteger",
+            DefaultFunction::AndByteString => "andByteString",
+            DefaultFunction::OrByteString => "orByteString",
+            DefaultFunction::XorByteString => "xorByteString",
+            DefaultFunction::ComplementByteString => "complementByteString",
+            DefaultFunction::ReadBit => "readBit",
+            DefaultFunction::WriteBits => "writeBits",
+            DefaultFunction::ReplicateByte => "replicateByte",
+            DefaultFunction::ShiftByteString => "shiftByteString",
+            DefaultFunction::RotateByteString => "rotateByteString",
+            DefaultFunction::CountSetBits => "countSetBits",
+            DefaultFunction::FindFirstSetBit => "findFirstSetBit",
+            DefaultFunction::Ripemd_160 => "ripemd_160",
+            DefaultFunction::ExpModInteger => "expModInteger",
+            DefaultFunction::DropList => "dropList",
+            DefaultFunction::LengthOfArray => "lengthOfArray",
+            DefaultFunction::ListToArray => "listToArray",
+            DefaultFunction::IndexArray => "indexArray",
+        };
+
+        f.write_str(name)
+    }
+}
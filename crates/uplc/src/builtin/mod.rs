@@ -1,3 +1,5 @@
+mod availability;
 mod default_function;
 
+pub use availability::*;
 pub use default_function::*;
@@ -55,7 +55,13 @@ impl<'a> PlutusData<'a> {
         arena: &'a Bump,
         cbor: &'_ [u8],
     ) -> Result<&'a PlutusData<'a>, minicbor::decode::Error> {
-        minicbor::decode_with(cbor, &mut Ctx { arena })
+        minicbor::decode_with(
+            cbor,
+            &mut Ctx {
+                arena,
+                version: None,
+            },
+        )
     }
 
     pub fn unwrap_constr<V>(
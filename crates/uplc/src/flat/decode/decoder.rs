@@ -3,7 +3,7 @@ use bumpalo::{
     Bump,
 };
 
-use crate::{constant::Integer, flat::zigzag::ZigZag};
+use crate::{constant::Integer, flat::zigzag::ZigZag, program::Version};
 
 use super::FlatDecodeError;
 
@@ -15,6 +15,8 @@ pub struct Decoder<'b> {
 
 pub struct Ctx<'a> {
     pub arena: &'a Bump,
+    /// Version of the program being decoded, constr and case need 1.1.0.
+    pub version: Option<&'a Version<'a>>,
 }
 
 impl<'b> Decoder<'b> {
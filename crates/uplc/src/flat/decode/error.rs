@@ -16,12 +16,16 @@ pub enum FlatDecodeError {
     DecodeUtf8(#[from] std::str::Utf8Error),
     #[error(transparent)]
     DecodeCbor(#[from] minicbor::decode::Error),
+    #[error(transparent)]
+    DecodeHex(#[from] hex::FromHexError),
     #[error("Decoding u32 to char {0}")]
     DecodeChar(u32),
     #[error("{0}")]
     Message(String),
     #[error("Default Function not found: {0}")]
     DefaultFunctionNotFound(u8),
+    #[error("{0} is not supported before 1.1.0")]
+    UnsupportedTerm(&'static str),
     #[error("Unknown term constructor tag: {0}")]
     UnknownTermConstructor(u8),
     #[error("Unknown constant constructor tag: {0:#?}")]
@@ -33,7 +33,10 @@ where
 
     let version = Version::new(arena, major, minor, patch);
 
-    let mut ctx = Ctx { arena };
+    let mut ctx = Ctx {
+        arena,
+        version: Some(version),
+    };
 
     let term = decode_term(&mut ctx, &mut decoder)?;
 
@@ -42,6 +45,55 @@ where
     Ok(Program::new(arena, version, term))
 }
 
+/// Decodes a script wrapped in one or two CBOR bytestrings, as found on chain
+/// and in blueprints. Raw flat bytes are accepted too.
+pub fn decode_cbor<'a, V>(
+    arena: &'a Bump,
+    bytes: &[u8],
+) -> Result<&'a Program<'a, V>, FlatDecodeError>
+where
+    V: Binder<'a>,
+{
+    decode(arena, unwrap_cbor(bytes)?)
+}
+
+/// Like [`decode_cbor`] but from a hex string.
+pub fn decode_hex<'a, V>(arena: &'a Bump, hex: &str) -> Result<&'a Program<'a, V>, FlatDecodeError>
+where
+    V: Binder<'a>,
+{
+    let bytes = hex::decode(hex.trim())?;
+
+    decode_cbor(arena, &bytes)
+}
+
+// A flat program starts with its major version word, which never looks like
+// a CBOR bytestring header, so the layers can be peeled until that shows up.
+fn unwrap_cbor(bytes: &[u8]) -> Result<&[u8], FlatDecodeError> {
+    let mut bytes = bytes;
+
+    for _ in 0..2 {
+        let mut decoder = minicbor::Decoder::new(bytes);
+
+        if decoder.datatype()? != minicbor::data::Type::Bytes {
+            break;
+        }
+
+        let inner = decoder.bytes()?;
+
+        if decoder.position() != bytes.len() {
+            return Err(FlatDecodeError::Message(format!(
+                "{} trailing bytes after CBOR wrapped script",
+                bytes.len() - decoder.position()
+            )));
+        }
+
+        bytes = inner;
+    }
+
+    Ok(bytes)
+}
+
 fn decode_term<'a, V>(
     ctx: &mut Ctx<'a>,
     decoder: &mut Decoder<'_>,
@@ -103,6 +155,13 @@ where
         }
         // Constr
         tag::CONSTR => {
+            if ctx
+                .version
+                .is_some_and(|version| version.is_less_than_1_1_0())
+            {
+                return Err(FlatDecodeError::UnsupportedTerm("constr"));
+            }
+
             let tag = decoder.word()?;
             let fields = decoder.list_with(ctx, decode_term)?;
             let fields = ctx.arena.alloc(fields);
@@ -113,6 +172,13 @@ where
         }
         // Case
         tag::CASE => {
+            if ctx
+                .version
+                .is_some_and(|version| version.is_less_than_1_1_0())
+            {
+                return Err(FlatDecodeError::UnsupportedTerm("case"));
+            }
+
             let constr = decode_term(ctx, decoder)?;
             let branches = decoder.list_with(ctx, decode_term)?;
             let branches = ctx.arena.alloc(branches);
@@ -263,7 +329,7 @@ mod tests {
                 );
             }
             Err(e) => {
-                assert!(false);
+                panic!("{}", e);
             }
         }
     }
@@ -341,4 +407,55 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn decode_cbor_peels_single_and_double_wrapping() {
+        let arena = Bump::new();
+
+        let expected: &Program<DeBruijn> =
+            crate::syn::parse_program(&arena, "(program 1.1.0 (con integer 1))")
+                .into_result()
+                .unwrap();
+
+        let flat = crate::flat::encode(expected).unwrap();
+        let single = crate::flat::encode_cbor(expected).unwrap();
+        let double = minicbor::to_vec(minicbor::bytes::ByteVec::from(single.clone())).unwrap();
+
+        assert_eq!(single[0], 0x40 | flat.len() as u8);
+        assert_eq!(&single[1..], &flat[..]);
+
+        for script in [flat, single, double] {
+            let program: &Program<DeBruijn> = decode_hex(&arena, &hex::encode(script)).unwrap();
+
+            assert_eq!(program, expected);
+        }
+    }
+
+    #[test]
+    fn decode_cbor_rejects_trailing_bytes() {
+        let arena = Bump::new();
+
+        let result: Result<&Program<DeBruijn>, _> = decode_hex(&arena, "45010100480100");
+
+        assert!(matches!(result, Err(FlatDecodeError::Message(_))));
+    }
+
+    #[test]
+    fn decode_rejects_constr_before_1_1_0() {
+        let arena = Bump::new();
+
+        let term = Term::constr(&arena, 0, arena.alloc([Term::integer_from(&arena, 1)]));
+
+        let program: &Program<DeBruijn> =
+            Program::new(&arena, crate::program::Version::plutus_v1(&arena), term);
+
+        let bytes = crate::flat::encode(program).unwrap();
+
+        let result: Result<&Program<DeBruijn>, _> = decode(&arena, &bytes);
+
+        assert!(matches!(
+            result,
+            Err(FlatDecodeError::UnsupportedTerm("constr"))
+        ));
+    }
 }
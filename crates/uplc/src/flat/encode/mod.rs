@@ -26,6 +26,21 @@ where
     Ok(encoder.buffer)
 }
 
+/// Encodes to flat wrapped in a single CBOR bytestring, the form scripts take
+/// in transactions and blueprints.
+pub fn encode_cbor<'a, V>(program: &'a Program<'a, V>) -> Result<Vec<u8>, FlatEncodeError>
+where
+    V: Binder<'a>,
+{
+    let flat = encode(program)?;
+
+    let mut encoder = minicbor::Encoder::new(Vec::new());
+
+    encoder.bytes(&flat)?;
+
+    Ok(encoder.into_writer())
+}
+
 fn encode_term<'a, V>(encoder: &mut Encoder, term: &'a Term<'a, V>) -> Result<(), FlatEncodeError>
 where
     V: Binder<'a>,
@@ -279,7 +294,7 @@ mod tests {
         //     ]
         //   ])
         let bytes_hex = "0101003370090011aab9d37549810cd8668218809f4100420101ff0001";
-        let bytes = hex::decode(&bytes_hex).unwrap();
+        let bytes = hex::decode(bytes_hex).unwrap();
         let arena = Bump::new();
         let program: Result<&Program<DeBruijn>, _> = decode(&arena, &bytes);
         match program {
@@ -290,12 +305,12 @@ mod tests {
                         assert_eq!(bytes_hex, hex::encode(roundtripped));
                     }
                     Err(e) => {
-                        assert!(false);
+                        panic!("{}", e);
                     }
                 }
             }
             Err(e) => {
-                assert!(false);
+                panic!("{}", e);
             }
         }
     }
@@ -321,7 +336,6 @@ mod tests {
         let bytes_hex =
             "0101003370090011bad357426aae78dd526112d8799fc24c033b2e3c9fd0803ce7ffffffff0001";
         let bytes = hex::decode(bytes_hex).unwrap();
-        let bytes = hex::decode(&bytes_hex).unwrap();
         let arena = Bump::new();
         let program: Result<&Program<DeBruijn>, _> = decode(&arena, &bytes);
         match program {
@@ -362,7 +376,6 @@ mod tests {
         //   ])
         let bytes_hex = "0101003370490021bad357426ae88dd62601049f070eff0001";
         let bytes = hex::decode(bytes_hex).unwrap();
-        let bytes = hex::decode(&bytes_hex).unwrap();
         let arena = Bump::new();
         let program: Result<&Program<DeBruijn>, _> = decode(&arena, &bytes);
         match program {
@@ -5,7 +5,9 @@ pub mod constant;
 pub mod data;
 pub mod flat;
 pub mod machine;
+pub mod pretty;
 pub mod program;
+pub mod scope;
 pub mod syn;
 pub mod term;
 pub mod typ;
@@ -0,0 +1,129 @@
+use bumpalo::Bump;
+
+use crate::{
+    binder::Eval,
+    program::{EvalOptions, Program},
+};
+
+use super::EvalResult;
+
+/// Evaluates programs one after another in a single arena, resetting it after
+/// each run so a long running validator keeps a bounded amount of memory.
+pub struct BatchMachine {
+    arena: Bump,
+    options: EvalOptions,
+    peak_arena_bytes: usize,
+}
+
+/// What a run handed back, along with the arena bytes it used.
+#[derive(Debug)]
+pub struct BatchRun<T> {
+    pub output: T,
+    pub arena_bytes: usize,
+}
+
+impl BatchMachine {
+    pub fn new(options: EvalOptions) -> Self {
+        Self::with_capacity(options, 0)
+    }
+
+    pub fn with_capacity(options: EvalOptions, capacity: usize) -> Self {
+        BatchMachine {
+            arena: Bump::with_capacity(capacity),
+            options,
+            peak_arena_bytes: 0,
+        }
+    }
+
+    pub fn options(&self) -> &EvalOptions {
+        &self.options
+    }
+
+    /// The most arena bytes any run so far has used.
+    pub fn peak_arena_bytes(&self) -> usize {
+        self.peak_arena_bytes
+    }
+
+    /// Builds a program in the arena with `program`, evaluates it and gives
+    /// the result to `output`. The arena is reset afterwards, so nothing
+    /// borrowed from it may outlive the run.
+    pub fn eval<V, E, T>(
+        &mut self,
+        program: impl for<'a> FnOnce(&'a Bump) -> Result<&'a Program<'a, V>, E>,
+        output: impl for<'a> FnOnce(EvalResult<'a, V>) -> T,
+    ) -> Result<BatchRun<T>, E>
+    where
+        V: for<'a> Eval<'a>,
+    {
+        let result = program(&self.arena)
+            .map(|program| output(program.eval_borrowing(&self.arena, &self.options)));
+
+        let arena_bytes = self
+            .arena
+            .iter_allocated_chunks()
+            .map(|chunk| chunk.len())
+            .sum();
+
+        self.peak_arena_bytes = self.peak_arena_bytes.max(arena_bytes);
+
+        self.arena.reset();
+
+        result.map(|output| BatchRun {
+            output,
+            arena_bytes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{binder::DeBruijn, flat, program::EvalOptions, syn};
+
+    use super::BatchMachine;
+
+    #[test]
+    fn eval_many_in_one_arena() {
+        let mut machine = BatchMachine::new(EvalOptions::default());
+
+        let script = flat::encode::<DeBruijn>(
+            syn::parse_program(
+                &bumpalo::Bump::new(),
+                "(program 1.1.0 [(lam x [(builtin addInteger) x x]) (con integer 21)])",
+            )
+            .into_result()
+            .unwrap(),
+        )
+        .unwrap();
+
+        let mut peak = None;
+
+        for _ in 0..100 {
+            let run = machine
+                .eval(
+                    |arena| flat::decode::<DeBruijn>(arena, &script),
+                    |result| result.term.ok().map(|term| term.to_pretty()),
+                )
+                .unwrap();
+
+            assert_eq!(run.output.as_deref(), Some("(con integer 42)"));
+            assert!(run.arena_bytes > 0);
+            assert!(run.arena_bytes <= machine.peak_arena_bytes());
+
+            // every run is the same, so the arena never grows past the first
+            assert_eq!(*peak.get_or_insert(run.arena_bytes), run.arena_bytes);
+            assert_eq!(machine.peak_arena_bytes(), run.arena_bytes);
+        }
+    }
+
+    #[test]
+    fn eval_reports_program_errors() {
+        let mut machine = BatchMachine::new(EvalOptions::default());
+
+        let run = machine.eval(
+            |arena| flat::decode::<DeBruijn>(arena, &[0x00]),
+            |result| result.info.consumed_budget,
+        );
+
+        assert!(run.is_err());
+    }
+}
@@ -4,6 +4,7 @@ use crate::{
     binder::Eval,
     constant::Constant,
     machine::{context::Context, env::Env, state::MachineState},
+    program::{EvalOptions, Version},
     term::Term,
 };
 
@@ -11,7 +12,8 @@ use super::{
     cost_model::StepKind,
     discharge,
     info::MachineInfo,
-    runtime::{BuiltinSemantics, Runtime},
+    observer::MachineObserver,
+    runtime::{BuiltinSemantics, PlutusVersion, ProtocolVersion, Runtime},
     value::Value,
     CostModel, ExBudget, MachineError,
 };
@@ -19,51 +21,87 @@ use super::{
 pub const CONS_BRANCH: usize = 0;
 pub const NILS_BRANCH: usize = 1;
 
-pub struct Machine<'a> {
+pub struct Machine<'a, O = ()> {
     pub(super) arena: &'a Bump,
     ex_budget: ExBudget,
     unbudgeted_steps: [u8; 10],
-    pub(super) costs: CostModel,
+    pub(super) costs: &'a CostModel,
     slippage: u8,
     pub(super) logs: Vec<String>,
     pub(super) semantics: BuiltinSemantics,
+    plutus_version: PlutusVersion,
+    protocol_version: ProtocolVersion,
+    /// Whether constr and case may be evaluated, only from language version 1.1.0.
+    sums_of_products: bool,
+    pub(super) observer: O,
 }
 
 impl<'a> Machine<'a> {
-    pub fn new(
+    /// A machine starting with `options.budget` and borrowing the cost model,
+    /// `options.tally` is left to [`Program::eval_with`](crate::program::Program::eval_with).
+    /// Terms are evaluated as of the language `version` of the program they
+    /// come from, so constr and case fail before 1.1.0.
+    pub fn new(arena: &'a Bump, options: &'a EvalOptions, version: &Version<'_>) -> Self {
+        Machine::with_observer(arena, options, version, ())
+    }
+}
+
+impl<'a, O> Machine<'a, O> {
+    pub fn with_observer(
         arena: &'a Bump,
-        initial_budget: ExBudget,
-        costs: CostModel,
-        semantics: BuiltinSemantics,
+        options: &'a EvalOptions,
+        version: &Version<'_>,
+        observer: O,
     ) -> Self {
         Machine {
             arena,
-            ex_budget: initial_budget,
+            ex_budget: options.budget,
             unbudgeted_steps: [0; 10],
-            costs,
+            costs: &options.cost_model,
             slippage: 200,
             logs: Vec::new(),
-            semantics,
+            semantics: BuiltinSemantics::from(&options.plutus_version),
+            plutus_version: options.plutus_version,
+            protocol_version: options.protocol_version,
+            sums_of_products: !version.is_less_than_1_1_0(),
+            observer,
         }
     }
 
-    pub fn info(self) -> MachineInfo {
-        MachineInfo {
+    pub fn observer(&self) -> &O {
+        &self.observer
+    }
+
+    pub fn observer_mut(&mut self) -> &mut O {
+        &mut self.observer
+    }
+
+    /// Like [`Machine::info`], also handing back the observer.
+    pub fn finish(self) -> (MachineInfo, O) {
+        let info = MachineInfo {
             consumed_budget: self.ex_budget,
             logs: self.logs,
-        }
+            tally: None,
+        };
+
+        (info, self.observer)
+    }
+
+    /// Messages traced so far.
+    pub fn logs(&self) -> &[String] {
+        &self.logs
+    }
+
+    pub fn info(self) -> MachineInfo {
+        self.finish().0
     }
 
     pub fn run<V>(&mut self, term: &'a Term<'a, V>) -> Result<&'a Term<'a, V>, MachineError<'a, V>>
     where
         V: Eval<'a>,
+        O: MachineObserver<'a, V>,
     {
-        self.spend_budget(ExBudget::start_up())?;
-
-        let initial_context = Context::no_frame(self.arena);
-
-        let mut state =
-            MachineState::compute(self.arena, initial_context, Env::new_in(self.arena), term);
+        let mut state = self.start(term)?;
 
         loop {
             let step = match state {
@@ -78,106 +116,170 @@ impl<'a> Machine<'a> {
         }
     }
 
+    /// Spends the startup cost and gives the state computing `term`, to be
+    /// advanced with [`Machine::step`].
+    pub fn start<V>(
+        &mut self,
+        term: &'a Term<'a, V>,
+    ) -> Result<MachineState<'a, V>, MachineError<'a, V>>
+    where
+        V: Eval<'a>,
+        O: MachineObserver<'a, V>,
+    {
+        self.spend_budget(self.costs.machine_costs.startup())?;
+
+        let initial_context = Context::no_frame(self.arena);
+
+        Ok(MachineState::compute(
+            initial_context,
+            Env::new_in(self.arena),
+            term,
+        ))
+    }
+
+    /// Takes a single transition, a done state is returned as is.
+    pub fn step<V>(
+        &mut self,
+        state: MachineState<'a, V>,
+    ) -> Result<MachineState<'a, V>, MachineError<'a, V>>
+    where
+        V: Eval<'a>,
+        O: MachineObserver<'a, V>,
+    {
+        match state {
+            MachineState::Compute(context, env, term) => self.compute(context, env, term),
+            MachineState::Return(context, value) => self.return_compute(context, value),
+            MachineState::Done(_) => Ok(state),
+        }
+    }
+
+    /// The budget left once the steps not yet charged for are spent.
+    pub fn remaining_budget(&self) -> ExBudget {
+        let mut budget = self.ex_budget;
+
+        for step_kind in 0..self.unbudgeted_steps.len() - 1 {
+            let mut step_budget = self.costs.machine_costs.get(step_kind);
+
+            step_budget.occurrences(self.unbudgeted_steps[step_kind] as i64);
+
+            budget = budget - step_budget;
+        }
+
+        budget
+    }
+
     pub fn compute<V>(
         &mut self,
         context: &'a Context<'a, V>,
         env: &'a Env<'a, V>,
         term: &'a Term<'a, V>,
-    ) -> Result<&'a mut MachineState<'a, V>, MachineError<'a, V>>
+    ) -> Result<MachineState<'a, V>, MachineError<'a, V>>
     where
         V: Eval<'a>,
+        O: MachineObserver<'a, V>,
     {
         match term {
             Term::Var(name) => {
-                self.step_and_maybe_spend(StepKind::Var)?;
+                self.step_and_maybe_spend(StepKind::Var, context)?;
 
                 let value = env
                     .lookup(name.index())
                     .ok_or(MachineError::OpenTermEvaluated(term))?;
 
-                let state = MachineState::return_(self.arena, context, value);
+                let state = MachineState::return_(context, value);
 
                 Ok(state)
             }
             Term::Lambda { parameter, body } => {
-                self.step_and_maybe_spend(StepKind::Lambda)?;
+                self.step_and_maybe_spend(StepKind::Lambda, context)?;
 
                 let value = Value::lambda(self.arena, *parameter, body, env);
 
-                let state = MachineState::return_(self.arena, context, value);
+                let state = MachineState::return_(context, value);
 
                 Ok(state)
             }
             Term::Apply { function, argument } => {
-                self.step_and_maybe_spend(StepKind::Apply)?;
+                self.step_and_maybe_spend(StepKind::Apply, context)?;
 
                 let frame = Context::frame_await_fun_term(self.arena, env, argument, context);
 
-                let state = MachineState::compute(self.arena, frame, env, function);
+                let state = MachineState::compute(frame, env, function);
 
                 Ok(state)
             }
             Term::Delay(body) => {
-                self.step_and_maybe_spend(StepKind::Delay)?;
+                self.step_and_maybe_spend(StepKind::Delay, context)?;
 
                 let value = Value::delay(self.arena, body, env);
 
-                let state = MachineState::return_(self.arena, context, value);
+                let state = MachineState::return_(context, value);
 
                 Ok(state)
             }
             Term::Force(body) => {
-                self.step_and_maybe_spend(StepKind::Force)?;
+                self.step_and_maybe_spend(StepKind::Force, context)?;
 
                 let frame = Context::frame_force(self.arena, context);
 
-                let state = MachineState::compute(self.arena, frame, env, body);
+                let state = MachineState::compute(frame, env, body);
 
                 Ok(state)
             }
             Term::Constr { tag, fields } => {
-                self.step_and_maybe_spend(StepKind::Constr)?;
+                if !self.sums_of_products {
+                    return Err(MachineError::UnsupportedTerm(term));
+                }
+
+                self.step_and_maybe_spend(StepKind::Constr, context)?;
 
                 if let Some((first, terms)) = fields.split_first() {
                     let frame = Context::frame_constr_empty(self.arena, env, *tag, terms, context);
 
-                    let state = MachineState::compute(self.arena, frame, env, first);
+                    let state = MachineState::compute(frame, env, first);
 
                     Ok(state)
                 } else {
                     let value = Value::constr_empty(self.arena, *tag);
 
-                    let state = MachineState::return_(self.arena, context, value);
+                    let state = MachineState::return_(context, value);
 
                     Ok(state)
                 }
             }
             Term::Case { constr, branches } => {
-                self.step_and_maybe_spend(StepKind::Case)?;
+                if !self.sums_of_products {
+                    return Err(MachineError::UnsupportedTerm(term));
+                }
+
+                self.step_and_maybe_spend(StepKind::Case, context)?;
 
                 let frame = Context::frame_cases(self.arena, env, branches, context);
 
-                let state = MachineState::compute(self.arena, frame, env, constr);
+                let state = MachineState::compute(frame, env, constr);
 
                 Ok(state)
             }
             Term::Constant(constant) => {
-                self.step_and_maybe_spend(StepKind::Constant)?;
+                self.step_and_maybe_spend(StepKind::Constant, context)?;
 
                 let value = Value::con(self.arena, constant);
 
-                let state = MachineState::return_(self.arena, context, value);
+                let state = MachineState::return_(context, value);
 
                 Ok(state)
             }
             Term::Builtin(fun) => {
-                self.step_and_maybe_spend(StepKind::Builtin)?;
+                fun.check_available(self.plutus_version, self.protocol_version)
+                    .map_err(MachineError::UnavailableBuiltin)?;
+
+                self.step_and_maybe_spend(StepKind::Builtin, context)?;
 
                 let runtime = Runtime::new(self.arena, fun);
 
                 let value = Value::builtin(self.arena, runtime);
 
-                let state = MachineState::return_(self.arena, context, value);
+                let state = MachineState::return_(context, value);
 
                 Ok(state)
             }
@@ -189,15 +291,18 @@ impl<'a> Machine<'a> {
         &mut self,
         context: &'a Context<'a, V>,
         value: &'a Value<'a, V>,
-    ) -> Result<&'a mut MachineState<'a, V>, MachineError<'a, V>>
+    ) -> Result<MachineState<'a, V>, MachineError<'a, V>>
     where
         V: Eval<'a>,
+        O: MachineObserver<'a, V>,
     {
+        self.observer.returned(context, value);
+
         match context {
             Context::FrameAwaitFunTerm(arg_env, argument, context) => {
                 let context = Context::frame_await_arg(self.arena, value, context);
 
-                let state = MachineState::compute(self.arena, context, arg_env, argument);
+                let state = MachineState::compute(context, arg_env, argument);
 
                 Ok(state)
             }
@@ -223,106 +328,153 @@ impl<'a> Machine<'a> {
                     let frame =
                         Context::frame_constr(self.arena, env, *tag, terms, values, context);
 
-                    let state = MachineState::compute(self.arena, frame, env, first);
+                    let state = MachineState::compute(frame, env, first);
 
                     Ok(state)
                 } else {
                     let value = Value::constr(self.arena, *tag, values);
 
-                    let state = MachineState::return_(self.arena, context, value);
+                    let state = MachineState::return_(context, value);
 
                     Ok(state)
                 }
             }
-            Context::FrameCases(env, branches, context) => match value {
-                Value::Constr(tag, fields) => {
-                    // kind of awkward tbh cause tag is already usize so how can it be bigger
-                    // than the max
-                    // if *tag > usize::MAX {
-                    //     return Err(MachineError::MaxConstrTagExceeded(value));
-                    // }
-
-                    if let Some(branch) = branches.get(*tag) {
-                        let frame = self.transfer_arg_stack(fields, context);
-
-                        let state = MachineState::compute(self.arena, frame, env, branch);
-
-                        Ok(state)
-                    } else {
-                        Err(MachineError::MissingCaseBranch(branches, value))
-                    }
-                }
-                Value::Con(constant) => match constant {
-                    Constant::Integer(scrutinee) => match usize::try_from(*scrutinee) {
-                        Ok(scrutinee_usize) => branches
-                            .get(scrutinee_usize)
-                            .map(|branch| MachineState::compute(self.arena, context, env, branch))
-                            .ok_or(MachineError::CekCaseBuiltinError(branches, value, format!("out of bounds for the given number of branches: {:?}", branches.len()))),
-                        Err(_) => Err(MachineError::CekCaseBuiltinError(branches, value, format!("out of bounds for the given number of branches: {:?}", branches.len())))
-                    },
-                    Constant::Boolean(scrutinee) => {
-                        if branches.len() > 2 {
-                            return Err(MachineError::CekCaseBuiltinError(branches, value, "caseing on bool requires exactly one branch or two branches".to_string()));
-                        }
-                        branches
-                            .get(*scrutinee as usize)
-                            .map(|branch| MachineState::compute(self.arena, context, env, branch))
-                            .ok_or(MachineError::CekCaseBuiltinError(branches, value, format!("out of bounds for the given number of branches: {:?}", branches.len())))
-                    }
-                    Constant::Unit => {
-                        if branches.len() > 1 {
-                            return Err(MachineError::CekCaseBuiltinError(branches, value, "caseing on unit only allows exactly one branch".to_string()));
+            Context::FrameCases(env, branches, context) => {
+                match value {
+                    Value::Constr(tag, fields) => {
+                        // kind of awkward tbh cause tag is already usize so how can it be bigger
+                        // than the max
+                        // if *tag > usize::MAX {
+                        //     return Err(MachineError::MaxConstrTagExceeded(value));
+                        // }
+
+                        if let Some(branch) = branches.get(*tag) {
+                            let frame = self.transfer_arg_stack(fields, context);
+
+                            let state = MachineState::compute(frame, env, branch);
+
+                            Ok(state)
+                        } else {
+                            Err(MachineError::MissingCaseBranch(branches, value))
                         }
-                        branches
-                            .get(CONS_BRANCH)
-                            .map(|branch| MachineState::compute(self.arena, context, env, branch))
-                            .ok_or(MachineError::CekCaseBuiltinError(branches, value, format!("out of bounds for the given number of branches: {:?}", branches.len())))
                     }
-                    // Caseing on pairs expects a single branch that takes two arguments for each values of the pair.
-                    Constant::ProtoPair(_, _, left_constant, right_constant) => {
-                        if branches.len() > 1 {
-                            return Err(MachineError::CekCaseBuiltinError(branches, value, "caseing on pair requires exactly one branch".to_string()));
-                        }
-                        branches
-                            .get(CONS_BRANCH)
-                            .map(|branch| {
-                                let right_value: &Value<'_, V> =
-                                    Value::con(self.arena, right_constant);
-                                let right_frame: &Context<'_, V> = Context::frame_await_fun_value(
-                                    self.arena,
-                                    right_value,
-                                    context,
-                                );
-                                let left_value = Value::con(self.arena, left_constant);
-                                let left_frame = Context::frame_await_fun_value(
-                                    self.arena,
-                                    left_value,
-                                    right_frame,
-                                );
-                                MachineState::compute(self.arena, left_frame, env, branch)
-                            })
-                            .ok_or(MachineError::CekCaseBuiltinError(branches, value, format!("out of bounds for the given number of branches: {:?}", branches.len())))
-                    }
-                    // When matching (case-ing) on a builtin list, exactly one or two branches are allowed:
-                    // - With a single branch, it is assumed the list is non-empty; the branch receives the head and tail as arguments.
-                    //   If the list is actually empty, script evaluation will fail.
-                    // - With two branches, the nils branch is selected for the empty list (receiving no arguments),
-                    //   and the cons branch is selected for a non-empty list (receiving the head and tail as arguments).
-                    //
-                    // Note: In the Haskell implementation, when a list contains only a single element,
-                    // the tail argument passed to the branch is an empty list.
-                    Constant::ProtoList(list_type, list) => {
-                        if branches.len() > 2 {
-                            return Err(MachineError::CekCaseBuiltinError(branches, value, "casing on list requires exactly one branch or two branches".to_string()));
-                        }
-
-                        match list.split_first() {
+                    Value::Con(constant) => {
+                        match constant {
+                            Constant::Integer(scrutinee) => match usize::try_from(*scrutinee) {
+                                Ok(scrutinee_usize) => branches
+                                    .get(scrutinee_usize)
+                                    .map(|branch| MachineState::compute(context, env, branch))
+                                    .ok_or(MachineError::CekCaseBuiltinError(
+                                        branches,
+                                        value,
+                                        format!(
+                                            "out of bounds for the given number of branches: {:?}",
+                                            branches.len()
+                                        ),
+                                    )),
+                                Err(_) => Err(MachineError::CekCaseBuiltinError(
+                                    branches,
+                                    value,
+                                    format!(
+                                        "out of bounds for the given number of branches: {:?}",
+                                        branches.len()
+                                    ),
+                                )),
+                            },
+                            Constant::Boolean(scrutinee) => {
+                                if branches.len() > 2 {
+                                    return Err(MachineError::CekCaseBuiltinError(branches, value, "caseing on bool requires exactly one branch or two branches".to_string()));
+                                }
+                                branches
+                                    .get(*scrutinee as usize)
+                                    .map(|branch| MachineState::compute(context, env, branch))
+                                    .ok_or(MachineError::CekCaseBuiltinError(
+                                        branches,
+                                        value,
+                                        format!(
+                                            "out of bounds for the given number of branches: {:?}",
+                                            branches.len()
+                                        ),
+                                    ))
+                            }
+                            Constant::Unit => {
+                                if branches.len() > 1 {
+                                    return Err(MachineError::CekCaseBuiltinError(
+                                        branches,
+                                        value,
+                                        "caseing on unit only allows exactly one branch"
+                                            .to_string(),
+                                    ));
+                                }
+                                branches
+                                    .get(CONS_BRANCH)
+                                    .map(|branch| MachineState::compute(context, env, branch))
+                                    .ok_or(MachineError::CekCaseBuiltinError(
+                                        branches,
+                                        value,
+                                        format!(
+                                            "out of bounds for the given number of branches: {:?}",
+                                            branches.len()
+                                        ),
+                                    ))
+                            }
+                            // Caseing on pairs expects a single branch that takes two arguments for each values of the pair.
+                            Constant::ProtoPair(_, _, left_constant, right_constant) => {
+                                if branches.len() > 1 {
+                                    return Err(MachineError::CekCaseBuiltinError(
+                                        branches,
+                                        value,
+                                        "caseing on pair requires exactly one branch".to_string(),
+                                    ));
+                                }
+                                branches
+                                    .get(CONS_BRANCH)
+                                    .map(|branch| {
+                                        let right_value: &Value<'_, V> =
+                                            Value::con(self.arena, right_constant);
+                                        let right_frame: &Context<'_, V> =
+                                            Context::frame_await_fun_value(
+                                                self.arena,
+                                                right_value,
+                                                context,
+                                            );
+                                        let left_value = Value::con(self.arena, left_constant);
+                                        let left_frame = Context::frame_await_fun_value(
+                                            self.arena,
+                                            left_value,
+                                            right_frame,
+                                        );
+                                        MachineState::compute(left_frame, env, branch)
+                                    })
+                                    .ok_or(MachineError::CekCaseBuiltinError(
+                                        branches,
+                                        value,
+                                        format!(
+                                            "out of bounds for the given number of branches: {:?}",
+                                            branches.len()
+                                        ),
+                                    ))
+                            }
+                            // When matching (case-ing) on a builtin list, exactly one or two branches are allowed:
+                            // - With a single branch, it is assumed the list is non-empty; the branch receives the head and tail as arguments.
+                            //   If the list is actually empty, script evaluation will fail.
+                            // - With two branches, the nils branch is selected for the empty list (receiving no arguments),
+                            //   and the cons branch is selected for a non-empty list (receiving the head and tail as arguments).
+                            //
+                            // Note: In the Haskell implementation, when a list contains only a single element,
+                            // the tail argument passed to the branch is an empty list.
+                            Constant::ProtoList(list_type, list) => {
+                                if branches.len() > 2 {
+                                    return Err(MachineError::CekCaseBuiltinError(branches, value, "casing on list requires exactly one branch or two branches".to_string()));
+                                }
+
+                                match list.split_first() {
                             None => {
                                 branches
                                 .get(NILS_BRANCH)
                                 .map(|branch| {
                                     let frame = self.transfer_arg_stack(&[], context);
-                                    MachineState::compute(self.arena, frame, env, branch)
+                                    MachineState::compute(frame, env, branch)
                                 })
                                 .ok_or(MachineError::CekCaseBuiltinError(branches, value, format!("out of bounds for the given number of branches: {:?}", branches.len())))
                             }
@@ -346,16 +498,22 @@ impl<'a> Machine<'a> {
                                         self.arena, head_value, tail_frame,
                                     );
 
-                                    MachineState::compute(self.arena, head_frame, env, branch)
+                                    MachineState::compute(head_frame, env, branch)
                                 })
                                 .ok_or(MachineError::CekCaseBuiltinError(branches, value, format!("out of bounds for the given number of branches: {:?}", branches.len())))
                             }
                         }
+                            }
+                            _ => Err(MachineError::CekCaseBuiltinError(
+                                branches,
+                                value,
+                                format!("cannot case on constant of type {constant:?}"),
+                            )),
+                        }
                     }
-                    _ => Err(MachineError::CekCaseBuiltinError(branches, value, format!("cannot case on constant of type {constant:?}"))),
-                },
-                v => Err(MachineError::NonConstrScrutinized(v)),
-            },
+                    v => Err(MachineError::NonConstrScrutinized(v)),
+                }
+            }
             Context::NoFrame => {
                 if self.unbudgeted_steps[9] > 0 {
                     self.spend_unbudgeted_steps()?;
@@ -363,7 +521,7 @@ impl<'a> Machine<'a> {
 
                 let term = discharge::value_as_term(self.arena, value);
 
-                let state = MachineState::done(self.arena, term);
+                let state = MachineState::done(term);
 
                 Ok(state)
             }
@@ -374,21 +532,22 @@ impl<'a> Machine<'a> {
         &mut self,
         context: &'a Context<'a, V>,
         value: &'a Value<'a, V>,
-    ) -> Result<&'a mut MachineState<'a, V>, MachineError<'a, V>>
+    ) -> Result<MachineState<'a, V>, MachineError<'a, V>>
     where
         V: Eval<'a>,
+        O: MachineObserver<'a, V>,
     {
         match value {
-            Value::Delay(term, env) => Ok(MachineState::compute(self.arena, context, env, term)),
+            Value::Delay(term, env) => Ok(MachineState::compute(context, env, term)),
             Value::Builtin(runtime) => {
                 if runtime.needs_force() {
                     let value = if runtime.is_ready() {
-                        self.call(runtime)?
+                        self.eval_builtin_app(runtime)?
                     } else {
                         Value::builtin(self.arena, runtime.force(self.arena))
                     };
 
-                    let state = MachineState::return_(self.arena, context, value);
+                    let state = MachineState::return_(context, value);
 
                     Ok(state)
                 } else {
@@ -406,15 +565,18 @@ impl<'a> Machine<'a> {
         context: &'a Context<'a, V>,
         function: &'a Value<'a, V>,
         argument: &'a Value<'a, V>,
-    ) -> Result<&'a mut MachineState<'a, V>, MachineError<'a, V>>
+    ) -> Result<MachineState<'a, V>, MachineError<'a, V>>
     where
         V: Eval<'a>,
+        O: MachineObserver<'a, V>,
     {
         match function {
             Value::Lambda { body, env, .. } => {
+                self.observer.lambda_applied(function, context);
+
                 let new_env = env.push(self.arena, argument);
 
-                let state = MachineState::compute(self.arena, context, new_env, body);
+                let state = MachineState::compute(context, new_env, body);
 
                 Ok(state)
             }
@@ -428,7 +590,7 @@ impl<'a> Machine<'a> {
                         Value::builtin(self.arena, runtime)
                     };
 
-                    let state = MachineState::return_(self.arena, context, value);
+                    let state = MachineState::return_(context, value);
 
                     Ok(state)
                 } else {
@@ -447,8 +609,16 @@ impl<'a> Machine<'a> {
     ) -> Result<&'a Value<'a, V>, MachineError<'a, V>>
     where
         V: Eval<'a>,
+        O: MachineObserver<'a, V>,
     {
-        self.call(runtime)
+        let budget = self.ex_budget;
+
+        let result = self.call(runtime);
+
+        self.observer
+            .builtin_call(*runtime.fun, &runtime.args, budget - self.ex_budget);
+
+        result
     }
 
     fn transfer_arg_stack<V>(
@@ -468,10 +638,17 @@ impl<'a> Machine<'a> {
         c
     }
 
-    fn step_and_maybe_spend<V>(&mut self, step: StepKind) -> Result<(), MachineError<'a, V>>
+    fn step_and_maybe_spend<V>(
+        &mut self,
+        step: StepKind,
+        context: &'a Context<'a, V>,
+    ) -> Result<(), MachineError<'a, V>>
     where
         V: Eval<'a>,
+        O: MachineObserver<'a, V>,
     {
+        self.observer.step(step, context);
+
         let index = step as usize;
 
         self.unbudgeted_steps[index] += 1;
@@ -487,12 +664,16 @@ impl<'a> Machine<'a> {
     fn spend_unbudgeted_steps<V>(&mut self) -> Result<(), MachineError<'a, V>>
     where
         V: Eval<'a>,
+        O: MachineObserver<'a, V>,
     {
         for step_kind in 0..self.unbudgeted_steps.len() - 1 {
             let mut unspent_step_budget = self.costs.machine_costs.get(step_kind);
 
             unspent_step_budget.occurrences(self.unbudgeted_steps[step_kind] as i64);
 
+            self.observer
+                .steps_spent(StepKind::ALL[step_kind], unspent_step_budget);
+
             self.spend_budget(unspent_step_budget)?;
 
             self.unbudgeted_steps[step_kind] = 0;
@@ -509,7 +690,10 @@ impl<'a> Machine<'a> {
     ) -> Result<(), MachineError<'a, V>>
     where
         V: Eval<'a>,
+        O: MachineObserver<'a, V>,
     {
+        self.observer.budget_spent(spend_budget);
+
         self.ex_budget.mem -= spend_budget.mem;
         self.ex_budget.cpu -= spend_budget.cpu;
 
@@ -520,3 +704,79 @@ impl<'a> Machine<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bumpalo::Bump;
+
+    use crate::{
+        binder::DeBruijn,
+        machine::MachineError,
+        program::{EvalOptions, Program, Version},
+        syn,
+        term::Term,
+    };
+
+    use super::Machine;
+
+    #[test]
+    fn step_matches_run() {
+        let arena = Bump::new();
+
+        let program: &Program<DeBruijn> = syn::parse_program(
+            &arena,
+            "(program 1.1.0 [(lam x [(builtin addInteger) x x]) (con integer 21)])",
+        )
+        .into_result()
+        .unwrap();
+
+        let options = EvalOptions::default();
+
+        let machine = || Machine::new(&arena, &options, program.version);
+
+        let mut running = machine();
+
+        let result = running.run(program.term).unwrap();
+
+        let mut stepping = machine();
+
+        let mut state = stepping.start(program.term).unwrap();
+        let mut steps = 0;
+
+        while !state.is_done() {
+            state = stepping.step(state).unwrap();
+            steps += 1;
+
+            if let Some(context) = state.context() {
+                assert!(context.frames().count() >= 1);
+            }
+        }
+
+        assert_eq!(steps, 15);
+        assert_eq!(state.term(), Some(result));
+        assert_eq!(state.term(), Some(Term::integer_from(&arena, 42)));
+        assert_eq!(stepping.remaining_budget(), running.remaining_budget());
+    }
+
+    #[test]
+    fn run_rejects_constr_before_1_1_0() {
+        let arena = Bump::new();
+
+        let term: &Term<DeBruijn> = syn::parse_term(&arena, "(constr 0 (con integer 1))")
+            .into_result()
+            .unwrap();
+
+        let options = EvalOptions::default();
+
+        let mut machine = Machine::new(&arena, &options, Version::plutus_v1(&arena));
+
+        assert!(matches!(
+            machine.run(term),
+            Err(MachineError::UnsupportedTerm(Term::Constr { .. }))
+        ));
+
+        let mut machine = Machine::new(&arena, &options, Version::plutus_v3(&arena));
+
+        assert!(machine.run(term).is_ok());
+    }
+}
@@ -27,6 +27,24 @@ impl<'a, V> Context<'a, V>
 where
     V: Eval<'a>,
 {
+    /// The frame this one returns to, `None` for `NoFrame`.
+    pub fn parent(&self) -> Option<&'a Context<'a, V>> {
+        match self {
+            Context::FrameAwaitArg(_, context)
+            | Context::FrameAwaitFunTerm(_, _, context)
+            | Context::FrameAwaitFunValue(_, context)
+            | Context::FrameForce(context)
+            | Context::FrameConstr(_, _, _, _, context)
+            | Context::FrameCases(_, _, context) => Some(context),
+            Context::NoFrame => None,
+        }
+    }
+
+    /// This frame followed by every frame below it, ending with `NoFrame`.
+    pub fn frames(&'a self) -> impl Iterator<Item = &'a Context<'a, V>> {
+        std::iter::successors(Some(self), |context| context.parent())
+    }
+
     pub fn no_frame(arena: &'a Bump) -> &'a Context<'a, V> {
         arena.alloc(Context::NoFrame)
     }
@@ -1,10 +1,17 @@
+use serde_json::{Map, Value};
+
 use crate::machine::ExBudget;
 
-use super::costing::{
-    Cost, OneArgumentCosting, SixArgumentsCosting, ThreeArgumentsCosting, TwoArgumentsCosting,
+use super::{
+    costing::{
+        Cost, Costing, OneArgumentCosting, SixArgumentsCosting, ThreeArgumentsCosting,
+        TwoArgumentsCosting,
+    },
+    params::{BuiltinParams, Params},
+    CostModelError,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct BuiltinCosts {
     add_integer: TwoArgumentsCosting,
     subtract_integer: TwoArgumentsCosting,
@@ -706,6 +713,35 @@ impl BuiltinCosts {
         )
     }
 
+    /// V1 and V2 are costed alike, V2 only adds builtins.
+    pub fn v1() -> Self {
+        BuiltinCosts::v2()
+    }
+
+    /// Builtins that came after V2 keep their V3 costs, V2 scripts can't
+    /// reach them anyway.
+    pub fn v2() -> Self {
+        Self {
+            divide_integer: TwoArgumentsCosting::new(
+                TwoArgumentsCosting::subtracted_sizes(0, 1, 1),
+                TwoArgumentsCosting::const_above_diagonal_into_multiplied_sizes(85848, 228465, 122),
+            ),
+            quotient_integer: TwoArgumentsCosting::new(
+                TwoArgumentsCosting::subtracted_sizes(0, 1, 1),
+                TwoArgumentsCosting::const_above_diagonal_into_multiplied_sizes(85848, 228465, 122),
+            ),
+            remainder_integer: TwoArgumentsCosting::new(
+                TwoArgumentsCosting::subtracted_sizes(0, 1, 1),
+                TwoArgumentsCosting::const_above_diagonal_into_multiplied_sizes(85848, 228465, 122),
+            ),
+            mod_integer: TwoArgumentsCosting::new(
+                TwoArgumentsCosting::subtracted_sizes(0, 1, 1),
+                TwoArgumentsCosting::const_above_diagonal_into_multiplied_sizes(85848, 228465, 122),
+            ),
+            ..BuiltinCosts::v3()
+        }
+    }
+
     pub fn v3() -> Self {
         Self {
             add_integer: TwoArgumentsCosting::new(
@@ -1088,4 +1124,481 @@ impl BuiltinCosts {
             ),
         }
     }
+
+    /// Builds the costs from named ledger parameters. Builtins without any
+    /// parameters, for example those a Plutus version predates, keep their V3
+    /// costs.
+    pub(super) fn from_params(params: &Params) -> Result<Self, CostModelError> {
+        let mut costs = BuiltinCosts::v3();
+
+        for (name, costing) in [
+            ("addInteger", &mut costs.add_integer),
+            ("subtractInteger", &mut costs.subtract_integer),
+        ] {
+            if let Some(p) = params.builtin(name) {
+                *costing = TwoArgumentsCosting::new(
+                    TwoArgumentsCosting::max_size(p.mem("intercept")?, p.mem("slope")?),
+                    TwoArgumentsCosting::max_size(p.cpu("intercept")?, p.cpu("slope")?),
+                );
+            }
+        }
+
+        if let Some(p) = params.builtin("multiplyInteger") {
+            costs.multiply_integer = TwoArgumentsCosting::new(
+                TwoArgumentsCosting::added_sizes(p.mem("intercept")?, p.mem("slope")?),
+                TwoArgumentsCosting::multiplied_sizes(p.cpu("intercept")?, p.cpu("slope")?),
+            );
+        }
+
+        for (name, costing) in [
+            ("divideInteger", &mut costs.divide_integer),
+            ("quotientInteger", &mut costs.quotient_integer),
+            ("remainderInteger", &mut costs.remainder_integer),
+            ("modInteger", &mut costs.mod_integer),
+        ] {
+            if let Some(p) = params.builtin(name) {
+                *costing = integer_division(&p)?;
+            }
+        }
+
+        for (name, costing) in [
+            ("equalsInteger", &mut costs.equals_integer),
+            ("lessThanInteger", &mut costs.less_than_integer),
+            ("lessThanEqualsInteger", &mut costs.less_than_equals_integer),
+            ("lessThanByteString", &mut costs.less_than_byte_string),
+            (
+                "lessThanEqualsByteString",
+                &mut costs.less_than_equals_byte_string,
+            ),
+            ("equalsData", &mut costs.equals_data),
+        ] {
+            if let Some(p) = params.builtin(name) {
+                *costing = TwoArgumentsCosting::new(
+                    TwoArgumentsCosting::constant_cost(p.mem_constant()?),
+                    TwoArgumentsCosting::min_size(p.cpu("intercept")?, p.cpu("slope")?),
+                );
+            }
+        }
+
+        for (name, costing) in [
+            ("appendByteString", &mut costs.append_byte_string),
+            ("appendString", &mut costs.append_string),
+        ] {
+            if let Some(p) = params.builtin(name) {
+                *costing = TwoArgumentsCosting::new(
+                    TwoArgumentsCosting::added_sizes(p.mem("intercept")?, p.mem("slope")?),
+                    TwoArgumentsCosting::added_sizes(p.cpu("intercept")?, p.cpu("slope")?),
+                );
+            }
+        }
+
+        if let Some(p) = params.builtin("consByteString") {
+            costs.cons_byte_string = TwoArgumentsCosting::new(
+                TwoArgumentsCosting::added_sizes(p.mem("intercept")?, p.mem("slope")?),
+                TwoArgumentsCosting::linear_in_y(p.cpu("intercept")?, p.cpu("slope")?),
+            );
+        }
+
+        if let Some(p) = params.builtin("sliceByteString") {
+            costs.slice_byte_string = ThreeArgumentsCosting::new(
+                ThreeArgumentsCosting::linear_in_z(p.mem("intercept")?, p.mem("slope")?),
+                ThreeArgumentsCosting::linear_in_z(p.cpu("intercept")?, p.cpu("slope")?),
+            );
+        }
+
+        for (name, costing) in [
+            ("equalsByteString", &mut costs.equals_byte_string),
+            ("equalsString", &mut costs.equals_string),
+        ] {
+            if let Some(p) = params.builtin(name) {
+                *costing = TwoArgumentsCosting::new(
+                    TwoArgumentsCosting::constant_cost(p.mem_constant()?),
+                    TwoArgumentsCosting::linear_on_diagonal(
+                        p.cpu("constant")?,
+                        p.cpu("intercept")?,
+                        p.cpu("slope")?,
+                    ),
+                );
+            }
+        }
+
+        for (name, costing) in [
+            ("sha2_256", &mut costs.sha2_256),
+            ("sha3_256", &mut costs.sha3_256),
+            ("blake2b_224", &mut costs.blake2b_224),
+            ("blake2b_256", &mut costs.blake2b_256),
+            ("keccak_256", &mut costs.keccak_256),
+            ("countSetBits", &mut costs.count_set_bits),
+            ("findFirstSetBit", &mut costs.find_first_set_bit),
+            ("ripemd_160", &mut costs.ripemd_160),
+        ] {
+            if let Some(p) = params.builtin(name) {
+                *costing = OneArgumentCosting::new(
+                    OneArgumentCosting::constant_cost(p.mem_constant()?),
+                    OneArgumentCosting::linear_cost(p.cpu("intercept")?, p.cpu("slope")?),
+                );
+            }
+        }
+
+        for (name, costing) in [
+            ("encodeUtf8", &mut costs.encode_utf8),
+            ("decodeUtf8", &mut costs.decode_utf8),
+            ("serialiseData", &mut costs.serialise_data),
+            ("complementByteString", &mut costs.complement_byte_string),
+        ] {
+            if let Some(p) = params.builtin(name) {
+                *costing = OneArgumentCosting::new(
+                    OneArgumentCosting::linear_cost(p.mem("intercept")?, p.mem("slope")?),
+                    OneArgumentCosting::linear_cost(p.cpu("intercept")?, p.cpu("slope")?),
+                );
+            }
+        }
+
+        for (name, costing) in [
+            (
+                "verifyEd25519Signature",
+                &mut costs.verify_ed25519_signature,
+            ),
+            (
+                "verifySchnorrSecp256k1Signature",
+                &mut costs.verify_schnorr_secp256k1_signature,
+            ),
+        ] {
+            if let Some(p) = params.builtin(name) {
+                *costing = ThreeArgumentsCosting::new(
+                    ThreeArgumentsCosting::constant_cost(p.mem_constant()?),
+                    ThreeArgumentsCosting::linear_in_y(p.cpu("intercept")?, p.cpu("slope")?),
+                );
+            }
+        }
+
+        for (name, costing) in [
+            ("lengthOfByteString", &mut costs.length_of_byte_string),
+            ("fstPair", &mut costs.fst_pair),
+            ("sndPair", &mut costs.snd_pair),
+            ("headList", &mut costs.head_list),
+            ("tailList", &mut costs.tail_list),
+            ("nullList", &mut costs.null_list),
+            ("mapData", &mut costs.map_data),
+            ("listData", &mut costs.list_data),
+            ("iData", &mut costs.i_data),
+            ("bData", &mut costs.b_data),
+            ("unConstrData", &mut costs.un_constr_data),
+            ("unMapData", &mut costs.un_map_data),
+            ("unListData", &mut costs.un_list_data),
+            ("unIData", &mut costs.un_i_data),
+            ("unBData", &mut costs.un_b_data),
+            ("mkNilData", &mut costs.mk_nil_data),
+            ("mkNilPairData", &mut costs.mk_nil_pair_data),
+            ("bls12_381_G1_neg", &mut costs.bls12_381_g1_neg),
+            ("bls12_381_G1_compress", &mut costs.bls12_381_g1_compress),
+            (
+                "bls12_381_G1_uncompress",
+                &mut costs.bls12_381_g1_uncompress,
+            ),
+            ("bls12_381_G2_neg", &mut costs.bls12_381_g2_neg),
+            ("bls12_381_G2_compress", &mut costs.bls12_381_g2_compress),
+            (
+                "bls12_381_G2_uncompress",
+                &mut costs.bls12_381_g2_uncompress,
+            ),
+        ] {
+            if let Some(p) = params.builtin(name) {
+                *costing = OneArgumentCosting::new(
+                    OneArgumentCosting::constant_cost(p.mem_constant()?),
+                    OneArgumentCosting::constant_cost(p.cpu_constant()?),
+                );
+            }
+        }
+
+        for (name, costing) in [
+            ("indexByteString", &mut costs.index_byte_string),
+            ("chooseUnit", &mut costs.choose_unit),
+            ("trace", &mut costs.trace),
+            ("mkCons", &mut costs.mk_cons),
+            ("constrData", &mut costs.constr_data),
+            ("mkPairData", &mut costs.mk_pair_data),
+            ("bls12_381_G1_add", &mut costs.bls12_381_g1_add),
+            ("bls12_381_G1_equal", &mut costs.bls12_381_g1_equal),
+            ("bls12_381_G2_add", &mut costs.bls12_381_g2_add),
+            ("bls12_381_G2_equal", &mut costs.bls12_381_g2_equal),
+            ("bls12_381_millerLoop", &mut costs.bls12_381_miller_loop),
+            ("bls12_381_mulMlResult", &mut costs.bls12_381_mul_ml_result),
+            ("bls12_381_finalVerify", &mut costs.bls12_381_final_verify),
+            ("readBit", &mut costs.read_bit),
+        ] {
+            if let Some(p) = params.builtin(name) {
+                *costing = TwoArgumentsCosting::new(
+                    TwoArgumentsCosting::constant_cost(p.mem_constant()?),
+                    TwoArgumentsCosting::constant_cost(p.cpu_constant()?),
+                );
+            }
+        }
+
+        for (name, costing) in [
+            ("ifThenElse", &mut costs.if_then_else),
+            ("chooseList", &mut costs.choose_list),
+            (
+                "verifyEcdsaSecp256k1Signature",
+                &mut costs.verify_ecdsa_secp256k1_signature,
+            ),
+        ] {
+            if let Some(p) = params.builtin(name) {
+                *costing = ThreeArgumentsCosting::new(
+                    ThreeArgumentsCosting::constant_cost(p.mem_constant()?),
+                    ThreeArgumentsCosting::constant_cost(p.cpu_constant()?),
+                );
+            }
+        }
+
+        if let Some(p) = params.builtin("chooseData") {
+            costs.choose_data = SixArgumentsCosting::new(
+                SixArgumentsCosting::constant_cost(p.mem_constant()?),
+                SixArgumentsCosting::constant_cost(p.cpu_constant()?),
+            );
+        }
+
+        for (name, costing) in [
+            ("bls12_381_G1_scalarMul", &mut costs.bls12_381_g1_scalar_mul),
+            (
+                "bls12_381_G1_hashToGroup",
+                &mut costs.bls12_381_g1_hash_to_group,
+            ),
+            ("bls12_381_G2_scalarMul", &mut costs.bls12_381_g2_scalar_mul),
+            (
+                "bls12_381_G2_hashToGroup",
+                &mut costs.bls12_381_g2_hash_to_group,
+            ),
+        ] {
+            if let Some(p) = params.builtin(name) {
+                *costing = TwoArgumentsCosting::new(
+                    TwoArgumentsCosting::constant_cost(p.mem_constant()?),
+                    TwoArgumentsCosting::linear_in_x(p.cpu("intercept")?, p.cpu("slope")?),
+                );
+            }
+        }
+
+        if let Some(p) = params.builtin("integerToByteString") {
+            costs.integer_to_byte_string = ThreeArgumentsCosting::new(
+                ThreeArgumentsCosting::literal_in_y_or_linear_in_z(
+                    p.mem("intercept")?,
+                    p.mem("slope")?,
+                ),
+                ThreeArgumentsCosting::quadratic_in_z(p.cpu("c0")?, p.cpu("c1")?, p.cpu("c2")?),
+            );
+        }
+
+        if let Some(p) = params.builtin("byteStringToInteger") {
+            costs.byte_string_to_integer = TwoArgumentsCosting::new(
+                TwoArgumentsCosting::linear_in_y(p.mem("intercept")?, p.mem("slope")?),
+                TwoArgumentsCosting::quadratic_in_y(p.cpu("c0")?, p.cpu("c1")?, p.cpu("c2")?),
+            );
+        }
+
+        for (name, costing) in [
+            ("andByteString", &mut costs.and_byte_string),
+            ("orByteString", &mut costs.or_byte_string),
+            ("xorByteString", &mut costs.xor_byte_string),
+        ] {
+            if let Some(p) = params.builtin(name) {
+                *costing = ThreeArgumentsCosting::new(
+                    ThreeArgumentsCosting::linear_in_max_y_z(p.mem("intercept")?, p.mem("slope")?),
+                    ThreeArgumentsCosting::linear_in_y_and_z(
+                        p.cpu("intercept")?,
+                        p.cpu("slope1")?,
+                        p.cpu("slope2")?,
+                    ),
+                );
+            }
+        }
+
+        if let Some(p) = params.builtin("writeBits") {
+            costs.write_bits = ThreeArgumentsCosting::new(
+                ThreeArgumentsCosting::linear_in_x(p.mem("intercept")?, p.mem("slope")?),
+                ThreeArgumentsCosting::linear_in_y(p.cpu("intercept")?, p.cpu("slope")?),
+            );
+        }
+
+        for (name, costing) in [
+            ("replicateByte", &mut costs.replicate_byte),
+            ("shiftByteString", &mut costs.shift_byte_string),
+            ("rotateByteString", &mut costs.rotate_byte_string),
+        ] {
+            if let Some(p) = params.builtin(name) {
+                *costing = TwoArgumentsCosting::new(
+                    TwoArgumentsCosting::linear_in_x(p.mem("intercept")?, p.mem("slope")?),
+                    TwoArgumentsCosting::linear_in_x(p.cpu("intercept")?, p.cpu("slope")?),
+                );
+            }
+        }
+
+        Ok(costs)
+    }
+}
+
+/// Before V3 the integer division builtins were costed with multiplied sizes
+/// above the diagonal and their memory never dropped under a minimum.
+fn integer_division(p: &BuiltinParams) -> Result<TwoArgumentsCosting, CostModelError> {
+    let mem = if p.has_mem("minimum") {
+        TwoArgumentsCosting::subtracted_sizes(
+            p.mem("intercept")?,
+            p.mem("slope")?,
+            p.mem("minimum")?,
+        )
+    } else {
+        TwoArgumentsCosting::linear_in_y(p.mem("intercept")?, p.mem("slope")?)
+    };
+
+    let cpu = if p.has_cpu("model-arguments-c00") {
+        TwoArgumentsCosting::const_above_diagonal_into_quadratic_x_and_y(
+            p.cpu("constant")?,
+            p.cpu("model-arguments-minimum")?,
+            p.cpu("model-arguments-c00")?,
+            p.cpu("model-arguments-c01")?,
+            p.cpu("model-arguments-c02")?,
+            p.cpu("model-arguments-c10")?,
+            p.cpu("model-arguments-c11")?,
+            p.cpu("model-arguments-c20")?,
+        )
+    } else {
+        TwoArgumentsCosting::const_above_diagonal_into_multiplied_sizes(
+            p.cpu("constant")?,
+            p.cpu("model-arguments-intercept")?,
+            p.cpu("model-arguments-slope")?,
+        )
+    };
+
+    Ok(TwoArgumentsCosting::new(mem, cpu))
+}
+
+macro_rules! json_fields {
+    ($($name:literal => $field:ident,)*) => {
+        impl BuiltinCosts {
+            /// Reads a `builtinCostModel.json` style object, builtins it leaves
+            /// out keep their current costs, those of the Plutus version
+            /// [`CostModel::from_json`](super::CostModel::from_json) starts from.
+            pub(super) fn update_from_json(&mut self, value: &Value) -> Result<(), CostModelError> {
+                let entries = value.as_object().ok_or_else(|| {
+                    CostModelError::Json("builtin costs must be an object".to_string())
+                })?;
+
+                for (name, costing) in entries {
+                    let invalid = |reason| CostModelError::InvalidModel {
+                        name: name.clone(),
+                        reason,
+                    };
+
+                    match name.as_str() {
+                        $($name => self.$field = Costing::from_json(costing).map_err(invalid)?,)*
+                        _ => return Err(CostModelError::UnknownParameter(name.clone())),
+                    }
+                }
+
+                Ok(())
+            }
+
+            pub(super) fn to_json(&self) -> Value {
+                let mut entries = Map::new();
+
+                $(entries.insert($name.to_string(), self.$field.to_json());)*
+
+                Value::Object(entries)
+            }
+        }
+    };
+}
+
+json_fields! {
+    "addInteger" => add_integer,
+    "subtractInteger" => subtract_integer,
+    "multiplyInteger" => multiply_integer,
+    "divideInteger" => divide_integer,
+    "quotientInteger" => quotient_integer,
+    "remainderInteger" => remainder_integer,
+    "modInteger" => mod_integer,
+    "equalsInteger" => equals_integer,
+    "lessThanInteger" => less_than_integer,
+    "lessThanEqualsInteger" => less_than_equals_integer,
+    "appendByteString" => append_byte_string,
+    "consByteString" => cons_byte_string,
+    "sliceByteString" => slice_byte_string,
+    "lengthOfByteString" => length_of_byte_string,
+    "indexByteString" => index_byte_string,
+    "equalsByteString" => equals_byte_string,
+    "lessThanByteString" => less_than_byte_string,
+    "lessThanEqualsByteString" => less_than_equals_byte_string,
+    "sha2_256" => sha2_256,
+    "sha3_256" => sha3_256,
+    "blake2b_224" => blake2b_224,
+    "blake2b_256" => blake2b_256,
+    "keccak_256" => keccak_256,
+    "verifyEd25519Signature" => verify_ed25519_signature,
+    "verifyEcdsaSecp256k1Signature" => verify_ecdsa_secp256k1_signature,
+    "verifySchnorrSecp256k1Signature" => verify_schnorr_secp256k1_signature,
+    "appendString" => append_string,
+    "equalsString" => equals_string,
+    "encodeUtf8" => encode_utf8,
+    "decodeUtf8" => decode_utf8,
+    "ifThenElse" => if_then_else,
+    "chooseUnit" => choose_unit,
+    "trace" => trace,
+    "fstPair" => fst_pair,
+    "sndPair" => snd_pair,
+    "chooseList" => choose_list,
+    "mkCons" => mk_cons,
+    "headList" => head_list,
+    "tailList" => tail_list,
+    "nullList" => null_list,
+    "chooseData" => choose_data,
+    "constrData" => constr_data,
+    "mapData" => map_data,
+    "listData" => list_data,
+    "iData" => i_data,
+    "bData" => b_data,
+    "unConstrData" => un_constr_data,
+    "unMapData" => un_map_data,
+    "unListData" => un_list_data,
+    "unIData" => un_i_data,
+    "unBData" => un_b_data,
+    "equalsData" => equals_data,
+    "mkPairData" => mk_pair_data,
+    "mkNilData" => mk_nil_data,
+    "mkNilPairData" => mk_nil_pair_data,
+    "serialiseData" => serialise_data,
+    "bls12_381_G1_add" => bls12_381_g1_add,
+    "bls12_381_G1_neg" => bls12_381_g1_neg,
+    "bls12_381_G1_scalarMul" => bls12_381_g1_scalar_mul,
+    "bls12_381_G1_equal" => bls12_381_g1_equal,
+    "bls12_381_G1_compress" => bls12_381_g1_compress,
+    "bls12_381_G1_uncompress" => bls12_381_g1_uncompress,
+    "bls12_381_G1_hashToGroup" => bls12_381_g1_hash_to_group,
+    "bls12_381_G2_add" => bls12_381_g2_add,
+    "bls12_381_G2_neg" => bls12_381_g2_neg,
+    "bls12_381_G2_scalarMul" => bls12_381_g2_scalar_mul,
+    "bls12_381_G2_equal" => bls12_381_g2_equal,
+    "bls12_381_G2_compress" => bls12_381_g2_compress,
+    "bls12_381_G2_uncompress" => bls12_381_g2_uncompress,
+    "bls12_381_G2_hashToGroup" => bls12_381_g2_hash_to_group,
+    "bls12_381_millerLoop" => bls12_381_miller_loop,
+    "bls12_381_mulMlResult" => bls12_381_mul_ml_result,
+    "bls12_381_finalVerify" => bls12_381_final_verify,
+    "integerToByteString" => integer_to_byte_string,
+    "byteStringToInteger" => byte_string_to_integer,
+    "andByteString" => and_byte_string,
+    "orByteString" => or_byte_string,
+    "xorByteString" => xor_byte_string,
+    "complementByteString" => complement_byte_string,
+    "readBit" => read_bit,
+    "writeBits" => write_bits,
+    "replicateByte" => replicate_byte,
+    "shiftByteString" => shift_byte_string,
+    "rotateByteString" => rotate_byte_string,
+    "countSetBits" => count_set_bits,
+    "findFirstSetBit" => find_first_set_bit,
+    "ripemd_160" => ripemd_160,
+    "expModInteger" => exp_mod_integer,
+    "dropList" => drop_list,
+    "lengthOfArray" => length_of_array,
+    "listToArray" => list_to_array,
+    "indexArray" => index_array,
 }
@@ -3,7 +3,7 @@ pub trait Cost<const N: usize> {
 }
 
 // Struct using the trait
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Costing<const N: usize, T: Cost<N>> {
     pub mem: T,
     pub cpu: T,
@@ -18,7 +18,7 @@ where
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum OneArgument {
     ConstantCost(i64),
     LinearCost(LinearSize),
@@ -47,7 +47,7 @@ impl OneArgumentCosting {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TwoArguments {
     ConstantCost(i64),
     LinearInX(LinearSize),
@@ -60,6 +60,7 @@ pub enum TwoArguments {
     LinearOnDiagonal(ConstantOrLinear),
     QuadraticInY(QuadraticFunction),
     ConstAboveDiagonalIntoQuadraticXAndY(i64, TwoArgumentsQuadraticFunction),
+    ConstAboveDiagonalIntoMultipliedSizes(i64, MultipliedSizes),
 }
 
 pub type TwoArgumentsCosting = Costing<2, TwoArguments>;
@@ -126,6 +127,17 @@ impl TwoArgumentsCosting {
         )
     }
 
+    pub fn const_above_diagonal_into_multiplied_sizes(
+        constant: i64,
+        intercept: i64,
+        slope: i64,
+    ) -> TwoArguments {
+        TwoArguments::ConstAboveDiagonalIntoMultipliedSizes(
+            constant,
+            MultipliedSizes { intercept, slope },
+        )
+    }
+
     pub fn linear_in_y(intercept: i64, slope: i64) -> TwoArguments {
         TwoArguments::LinearInY(LinearSize { intercept, slope })
     }
@@ -180,11 +192,18 @@ impl Cost<2> for TwoArguments {
                     )
                 }
             }
+            TwoArguments::ConstAboveDiagonalIntoMultipliedSizes(constant, s) => {
+                if x < y {
+                    *constant
+                } else {
+                    s.slope * (x * y) + s.intercept
+                }
+            }
         }
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ThreeArguments {
     ConstantCost(i64),
     // AddedSizes(AddedSizes),
@@ -284,7 +303,7 @@ impl Cost<3> for ThreeArguments {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum SixArguments {
     ConstantCost(i64),
 }
@@ -305,78 +324,78 @@ impl Cost<6> for SixArguments {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct LinearSize {
     pub intercept: i64,
     pub slope: i64,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TwoVariableLinearSize {
     pub intercept: i64,
     pub slope1: i64,
     pub slope2: i64,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AddedSizes {
     pub intercept: i64,
     pub slope: i64,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SubtractedSizes {
     pub intercept: i64,
     pub slope: i64,
     pub minimum: i64,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct MultipliedSizes {
     pub intercept: i64,
     pub slope: i64,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct MinSize {
     pub intercept: i64,
     pub slope: i64,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct MaxSize {
     pub intercept: i64,
     pub slope: i64,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ConstantOrLinear {
     pub constant: i64,
     pub intercept: i64,
     pub slope: i64,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct QuadraticFunction {
-    coeff_0: i64,
-    coeff_1: i64,
-    coeff_2: i64,
+    pub coeff_0: i64,
+    pub coeff_1: i64,
+    pub coeff_2: i64,
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct TwoArgumentsQuadraticFunction {
-    minimum: i64,
-    coeff_00: i64,
-    coeff_01: i64,
-    coeff_02: i64,
-    coeff_10: i64,
-    coeff_11: i64,
-    coeff_20: i64,
+    pub minimum: i64,
+    pub coeff_00: i64,
+    pub coeff_01: i64,
+    pub coeff_02: i64,
+    pub coeff_10: i64,
+    pub coeff_11: i64,
+    pub coeff_20: i64,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ExpModCost {
-    coeff_00: i64,
-    coeff_11: i64,
-    coeff_12: i64,
+    pub coeff_00: i64,
+    pub coeff_11: i64,
+    pub coeff_12: i64,
 }
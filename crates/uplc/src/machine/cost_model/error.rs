@@ -0,0 +1,27 @@
+use crate::machine::PlutusVersion;
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum CostModelError {
+    #[error("{version:?} cost model takes {} parameters, found {actual}", display_lengths(.expected))]
+    WrongLength {
+        version: PlutusVersion,
+        expected: &'static [usize],
+        actual: usize,
+    },
+    #[error("missing cost model parameter {0}")]
+    MissingParameter(String),
+    #[error("unknown cost model parameter {0}")]
+    UnknownParameter(String),
+    #[error("invalid cost model for {name}: {reason}")]
+    InvalidModel { name: String, reason: String },
+    #[error("invalid cost model json: {0}")]
+    Json(String),
+}
+
+fn display_lengths(lengths: &[usize]) -> String {
+    lengths
+        .iter()
+        .map(|length| length.to_string())
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
@@ -106,6 +106,17 @@ impl ExBudget {
     }
 }
 
+impl std::ops::Add for ExBudget {
+    type Output = ExBudget;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        ExBudget {
+            mem: self.mem + rhs.mem,
+            cpu: self.cpu + rhs.cpu,
+        }
+    }
+}
+
 impl std::ops::Sub for ExBudget {
     type Output = ExBudget;
 
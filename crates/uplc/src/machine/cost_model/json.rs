@@ -0,0 +1,321 @@
+//! The named-parameter JSON format Plutus publishes its cost models in, as
+//! in `builtinCostModel.json` and `cekMachineCosts.json`.
+
+use serde_json::{json, Value};
+
+use super::costing::{
+    AddedSizes, ConstantOrLinear, Cost, Costing, ExpModCost, LinearSize, MaxSize, MinSize,
+    MultipliedSizes, OneArgument, QuadraticFunction, SixArguments, SubtractedSizes, ThreeArguments,
+    TwoArguments, TwoArgumentsQuadraticFunction, TwoVariableLinearSize,
+};
+
+pub(super) trait Model: Sized {
+    fn from_json(value: &Value) -> Result<Self, String>;
+
+    fn to_json(&self) -> Value;
+}
+
+impl<const N: usize, T> Costing<N, T>
+where
+    T: Cost<N> + Model,
+{
+    pub(super) fn from_json(value: &Value) -> Result<Self, String> {
+        let mem = value.get("memory").ok_or("missing memory model")?;
+        let cpu = value.get("cpu").ok_or("missing cpu model")?;
+
+        Ok(Costing::new(T::from_json(mem)?, T::from_json(cpu)?))
+    }
+
+    pub(super) fn to_json(&self) -> Value {
+        json!({
+            "cpu": self.cpu.to_json(),
+            "memory": self.mem.to_json(),
+        })
+    }
+}
+
+fn split(value: &Value) -> Result<(&str, &Value), String> {
+    let kind = value
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or("missing model type")?;
+
+    let arguments = value.get("arguments").ok_or("missing model arguments")?;
+
+    Ok((kind, arguments))
+}
+
+fn model(kind: &str, arguments: Value) -> Value {
+    json!({
+        "arguments": arguments,
+        "type": kind,
+    })
+}
+
+fn arg(arguments: &Value, name: &str) -> Result<i64, String> {
+    arguments
+        .get(name)
+        .and_then(Value::as_i64)
+        .ok_or_else(|| format!("missing integer argument {name}"))
+}
+
+fn constant(arguments: &Value) -> Result<i64, String> {
+    arguments
+        .as_i64()
+        .ok_or_else(|| "constant_cost takes a single integer".to_string())
+}
+
+fn linear(arguments: &Value) -> Result<LinearSize, String> {
+    Ok(LinearSize {
+        intercept: arg(arguments, "intercept")?,
+        slope: arg(arguments, "slope")?,
+    })
+}
+
+fn linear_json(l: &LinearSize) -> Value {
+    json!({ "intercept": l.intercept, "slope": l.slope })
+}
+
+fn quadratic(arguments: &Value) -> Result<QuadraticFunction, String> {
+    Ok(QuadraticFunction {
+        coeff_0: arg(arguments, "c0")?,
+        coeff_1: arg(arguments, "c1")?,
+        coeff_2: arg(arguments, "c2")?,
+    })
+}
+
+fn quadratic_json(q: &QuadraticFunction) -> Value {
+    json!({ "c0": q.coeff_0, "c1": q.coeff_1, "c2": q.coeff_2 })
+}
+
+fn unknown(kind: &str) -> String {
+    format!("unknown model type {kind}")
+}
+
+impl Model for OneArgument {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let (kind, arguments) = split(value)?;
+
+        match kind {
+            "constant_cost" => Ok(OneArgument::ConstantCost(constant(arguments)?)),
+            "linear_in_x" => Ok(OneArgument::LinearCost(linear(arguments)?)),
+            _ => Err(unknown(kind)),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            OneArgument::ConstantCost(c) => model("constant_cost", json!(c)),
+            OneArgument::LinearCost(l) => model("linear_in_x", linear_json(l)),
+        }
+    }
+}
+
+impl Model for TwoArguments {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let (kind, arguments) = split(value)?;
+
+        let model = match kind {
+            "constant_cost" => TwoArguments::ConstantCost(constant(arguments)?),
+            "linear_in_x" => TwoArguments::LinearInX(linear(arguments)?),
+            "linear_in_y" => TwoArguments::LinearInY(linear(arguments)?),
+            "added_sizes" => TwoArguments::AddedSizes(AddedSizes {
+                intercept: arg(arguments, "intercept")?,
+                slope: arg(arguments, "slope")?,
+            }),
+            "subtracted_sizes" => TwoArguments::SubtractedSizes(SubtractedSizes {
+                intercept: arg(arguments, "intercept")?,
+                slope: arg(arguments, "slope")?,
+                minimum: arg(arguments, "minimum")?,
+            }),
+            "multiplied_sizes" => TwoArguments::MultipliedSizes(MultipliedSizes {
+                intercept: arg(arguments, "intercept")?,
+                slope: arg(arguments, "slope")?,
+            }),
+            "min_size" => TwoArguments::MinSize(MinSize {
+                intercept: arg(arguments, "intercept")?,
+                slope: arg(arguments, "slope")?,
+            }),
+            "max_size" => TwoArguments::MaxSize(MaxSize {
+                intercept: arg(arguments, "intercept")?,
+                slope: arg(arguments, "slope")?,
+            }),
+            "linear_on_diagonal" => TwoArguments::LinearOnDiagonal(ConstantOrLinear {
+                constant: arg(arguments, "constant")?,
+                intercept: arg(arguments, "intercept")?,
+                slope: arg(arguments, "slope")?,
+            }),
+            "quadratic_in_y" => TwoArguments::QuadraticInY(quadratic(arguments)?),
+            "const_above_diagonal" => {
+                let constant = arg(arguments, "constant")?;
+
+                let inner = arguments
+                    .get("model")
+                    .ok_or("const_above_diagonal is missing its model")?;
+
+                let (inner_kind, inner_arguments) = split(inner)?;
+
+                match inner_kind {
+                    "quadratic_in_x_and_y" => TwoArguments::ConstAboveDiagonalIntoQuadraticXAndY(
+                        constant,
+                        TwoArgumentsQuadraticFunction {
+                            minimum: arg(inner_arguments, "minimum")?,
+                            coeff_00: arg(inner_arguments, "c00")?,
+                            coeff_01: arg(inner_arguments, "c01")?,
+                            coeff_02: arg(inner_arguments, "c02")?,
+                            coeff_10: arg(inner_arguments, "c10")?,
+                            coeff_11: arg(inner_arguments, "c11")?,
+                            coeff_20: arg(inner_arguments, "c20")?,
+                        },
+                    ),
+                    "multiplied_sizes" => TwoArguments::ConstAboveDiagonalIntoMultipliedSizes(
+                        constant,
+                        MultipliedSizes {
+                            intercept: arg(inner_arguments, "intercept")?,
+                            slope: arg(inner_arguments, "slope")?,
+                        },
+                    ),
+                    _ => return Err(unknown(inner_kind)),
+                }
+            }
+            _ => return Err(unknown(kind)),
+        };
+
+        Ok(model)
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            TwoArguments::ConstantCost(c) => model("constant_cost", json!(c)),
+            TwoArguments::LinearInX(l) => model("linear_in_x", linear_json(l)),
+            TwoArguments::LinearInY(l) => model("linear_in_y", linear_json(l)),
+            TwoArguments::AddedSizes(s) => model(
+                "added_sizes",
+                json!({ "intercept": s.intercept, "slope": s.slope }),
+            ),
+            TwoArguments::SubtractedSizes(s) => model(
+                "subtracted_sizes",
+                json!({ "intercept": s.intercept, "minimum": s.minimum, "slope": s.slope }),
+            ),
+            TwoArguments::MultipliedSizes(s) => model(
+                "multiplied_sizes",
+                json!({ "intercept": s.intercept, "slope": s.slope }),
+            ),
+            TwoArguments::MinSize(s) => model(
+                "min_size",
+                json!({ "intercept": s.intercept, "slope": s.slope }),
+            ),
+            TwoArguments::MaxSize(s) => model(
+                "max_size",
+                json!({ "intercept": s.intercept, "slope": s.slope }),
+            ),
+            TwoArguments::LinearOnDiagonal(l) => model(
+                "linear_on_diagonal",
+                json!({ "constant": l.constant, "intercept": l.intercept, "slope": l.slope }),
+            ),
+            TwoArguments::QuadraticInY(q) => model("quadratic_in_y", quadratic_json(q)),
+            TwoArguments::ConstAboveDiagonalIntoQuadraticXAndY(constant, q) => model(
+                "const_above_diagonal",
+                json!({
+                    "constant": constant,
+                    "model": model(
+                        "quadratic_in_x_and_y",
+                        json!({
+                            "c00": q.coeff_00,
+                            "c01": q.coeff_01,
+                            "c02": q.coeff_02,
+                            "c10": q.coeff_10,
+                            "c11": q.coeff_11,
+                            "c20": q.coeff_20,
+                            "minimum": q.minimum,
+                        }),
+                    ),
+                }),
+            ),
+            TwoArguments::ConstAboveDiagonalIntoMultipliedSizes(constant, s) => model(
+                "const_above_diagonal",
+                json!({
+                    "constant": constant,
+                    "model": model(
+                        "multiplied_sizes",
+                        json!({ "intercept": s.intercept, "slope": s.slope }),
+                    ),
+                }),
+            ),
+        }
+    }
+}
+
+impl Model for ThreeArguments {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let (kind, arguments) = split(value)?;
+
+        let model = match kind {
+            "constant_cost" => ThreeArguments::ConstantCost(constant(arguments)?),
+            "linear_in_x" => ThreeArguments::LinearInX(linear(arguments)?),
+            "linear_in_y" => ThreeArguments::LinearInY(linear(arguments)?),
+            "linear_in_z" => ThreeArguments::LinearInZ(linear(arguments)?),
+            "quadratic_in_z" => ThreeArguments::QuadraticInZ(quadratic(arguments)?),
+            "literal_in_y_or_linear_in_z" => {
+                ThreeArguments::LiteralInYorLinearInZ(linear(arguments)?)
+            }
+            "linear_in_y_and_z" => ThreeArguments::LinearInYAndZ(TwoVariableLinearSize {
+                intercept: arg(arguments, "intercept")?,
+                slope1: arg(arguments, "slope1")?,
+                slope2: arg(arguments, "slope2")?,
+            }),
+            "linear_in_max_yz" => ThreeArguments::LinearInMaxYZ(linear(arguments)?),
+            "exp_mod_cost" => ThreeArguments::ExpModCost(ExpModCost {
+                coeff_00: arg(arguments, "coefficient00")?,
+                coeff_11: arg(arguments, "coefficient11")?,
+                coeff_12: arg(arguments, "coefficient12")?,
+            }),
+            _ => return Err(unknown(kind)),
+        };
+
+        Ok(model)
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            ThreeArguments::ConstantCost(c) => model("constant_cost", json!(c)),
+            ThreeArguments::LinearInX(l) => model("linear_in_x", linear_json(l)),
+            ThreeArguments::LinearInY(l) => model("linear_in_y", linear_json(l)),
+            ThreeArguments::LinearInZ(l) => model("linear_in_z", linear_json(l)),
+            ThreeArguments::QuadraticInZ(q) => model("quadratic_in_z", quadratic_json(q)),
+            ThreeArguments::LiteralInYorLinearInZ(l) => {
+                model("literal_in_y_or_linear_in_z", linear_json(l))
+            }
+            ThreeArguments::LinearInYAndZ(l) => model(
+                "linear_in_y_and_z",
+                json!({ "intercept": l.intercept, "slope1": l.slope1, "slope2": l.slope2 }),
+            ),
+            ThreeArguments::LinearInMaxYZ(l) => model("linear_in_max_yz", linear_json(l)),
+            ThreeArguments::ExpModCost(c) => model(
+                "exp_mod_cost",
+                json!({
+                    "coefficient00": c.coeff_00,
+                    "coefficient11": c.coeff_11,
+                    "coefficient12": c.coeff_12,
+                }),
+            ),
+        }
+    }
+}
+
+impl Model for SixArguments {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let (kind, arguments) = split(value)?;
+
+        match kind {
+            "constant_cost" => Ok(SixArguments::ConstantCost(constant(arguments)?)),
+            _ => Err(unknown(kind)),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            SixArguments::ConstantCost(c) => model("constant_cost", json!(c)),
+        }
+    }
+}
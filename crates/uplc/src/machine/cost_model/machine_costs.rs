@@ -1,7 +1,19 @@
+use serde_json::{json, Map, Value};
+
 use crate::machine::ExBudget;
 
-#[derive(Debug, PartialEq)]
-pub struct MachineCosts([ExBudget; 9]);
+use super::{params::Params, CostModelError};
+
+/// Ledger names of the step costs, in `StepKind` order.
+const STEPS: [&str; 9] = [
+    "Const", "Var", "Lam", "Apply", "Delay", "Force", "Builtin", "Constr", "Case",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MachineCosts {
+    startup: ExBudget,
+    steps: [ExBudget; 9],
+}
 
 impl Default for MachineCosts {
     fn default() -> Self {
@@ -11,20 +23,103 @@ impl Default for MachineCosts {
 
 impl MachineCosts {
     pub fn new() -> Self {
-        MachineCosts([
-            ExBudget::constant(),
-            ExBudget::var(),
-            ExBudget::lambda(),
-            ExBudget::apply(),
-            ExBudget::delay(),
-            ExBudget::force(),
-            ExBudget::builtin(),
-            ExBudget::constr(),
-            ExBudget::case(),
-        ])
+        MachineCosts {
+            startup: ExBudget::start_up(),
+            steps: [
+                ExBudget::constant(),
+                ExBudget::var(),
+                ExBudget::lambda(),
+                ExBudget::apply(),
+                ExBudget::delay(),
+                ExBudget::force(),
+                ExBudget::builtin(),
+                ExBudget::constr(),
+                ExBudget::case(),
+            ],
+        }
     }
 
     pub fn get(&self, index: usize) -> ExBudget {
-        self.0[index]
+        self.steps[index]
+    }
+
+    pub fn startup(&self) -> ExBudget {
+        self.startup
+    }
+
+    /// Steps missing from `params`, like constr and case before V3, keep
+    /// their default cost.
+    pub(super) fn from_params(params: &Params) -> Result<Self, CostModelError> {
+        let mut costs = MachineCosts::new();
+
+        let step = |name: &str, default: ExBudget| -> Result<ExBudget, CostModelError> {
+            let cpu = format!("cek{name}Cost-exBudgetCPU");
+            let mem = format!("cek{name}Cost-exBudgetMemory");
+
+            if params.contains(&cpu) || params.contains(&mem) {
+                Ok(ExBudget::new(params.get(&mem)?, params.get(&cpu)?))
+            } else {
+                Ok(default)
+            }
+        };
+
+        costs.startup = step("Startup", costs.startup)?;
+
+        for (index, name) in STEPS.into_iter().enumerate() {
+            costs.steps[index] = step(name, costs.steps[index])?;
+        }
+
+        Ok(costs)
+    }
+
+    /// Reads a `cekMachineCosts.json` style object, steps it leaves out keep
+    /// their current cost.
+    pub(super) fn update_from_json(&mut self, value: &Value) -> Result<(), CostModelError> {
+        let entries = value
+            .as_object()
+            .ok_or_else(|| CostModelError::Json("machine costs must be an object".to_string()))?;
+
+        for (name, budget) in entries {
+            let slot = match name.as_str() {
+                "cekStartupCost" => &mut self.startup,
+                _ => STEPS
+                    .iter()
+                    .position(|step| name == &format!("cek{step}Cost"))
+                    .map(|index| &mut self.steps[index])
+                    .ok_or_else(|| CostModelError::UnknownParameter(name.clone()))?,
+            };
+
+            let field = |field: &str| {
+                budget.get(field).and_then(Value::as_i64).ok_or_else(|| {
+                    CostModelError::InvalidModel {
+                        name: name.clone(),
+                        reason: format!("missing integer {field}"),
+                    }
+                })
+            };
+
+            *slot = ExBudget::new(field("exBudgetMemory")?, field("exBudgetCPU")?);
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn to_json(&self) -> Value {
+        let budget = |budget: ExBudget| {
+            json!({
+                "exBudgetCPU": budget.cpu,
+                "exBudgetMemory": budget.mem,
+            })
+        };
+
+        let mut entries = Map::new();
+
+        entries.insert("cekStartupCost".to_string(), budget(self.startup));
+
+        for (name, step) in STEPS.iter().zip(self.steps) {
+            entries.insert(format!("cek{name}Cost"), budget(step));
+        }
+
+        Value::Object(entries)
     }
 }
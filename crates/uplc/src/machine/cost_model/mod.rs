@@ -1,17 +1,119 @@
 pub mod builtin_costs;
 mod costing;
+mod error;
 pub mod ex_budget;
+mod json;
 mod machine_costs;
+mod params;
 mod value;
 
+pub use error::CostModelError;
+pub(crate) use machine_costs::MachineCosts;
 pub use value::*;
 
-#[derive(Default, Debug, PartialEq)]
+use super::PlutusVersion;
+use params::Params;
+
+#[derive(Default, Debug, Clone, PartialEq)]
 pub struct CostModel {
     pub machine_costs: machine_costs::MachineCosts,
     pub builtin_costs: builtin_costs::BuiltinCosts,
 }
 
+impl CostModel {
+    pub fn v1() -> Self {
+        CostModel {
+            machine_costs: machine_costs::MachineCosts::new(),
+            builtin_costs: builtin_costs::BuiltinCosts::v1(),
+        }
+    }
+
+    pub fn v2() -> Self {
+        CostModel {
+            machine_costs: machine_costs::MachineCosts::new(),
+            builtin_costs: builtin_costs::BuiltinCosts::v2(),
+        }
+    }
+
+    pub fn v3() -> Self {
+        CostModel {
+            machine_costs: machine_costs::MachineCosts::new(),
+            builtin_costs: builtin_costs::BuiltinCosts::v3(),
+        }
+    }
+
+    /// The ledger's current cost model for scripts of `version`. The machine
+    /// step costs are the same for every version.
+    pub fn for_version(version: PlutusVersion) -> Self {
+        match version {
+            PlutusVersion::V1 => CostModel::v1(),
+            PlutusVersion::V2 => CostModel::v2(),
+            PlutusVersion::V3 => CostModel::v3(),
+        }
+    }
+
+    /// Reads the cost model of a Plutus version as found in the ledger's
+    /// protocol parameters.
+    pub fn from_plutus_params(
+        version: PlutusVersion,
+        params: &[i64],
+    ) -> Result<Self, CostModelError> {
+        let params = Params::from_array(version, params)?;
+
+        Ok(CostModel {
+            machine_costs: machine_costs::MachineCosts::from_params(&params)?,
+            builtin_costs: builtin_costs::BuiltinCosts::from_params(&params)?,
+        })
+    }
+
+    pub fn from_plutus_v1_params(params: &[i64]) -> Result<Self, CostModelError> {
+        Self::from_plutus_params(PlutusVersion::V1, params)
+    }
+
+    pub fn from_plutus_v2_params(params: &[i64]) -> Result<Self, CostModelError> {
+        Self::from_plutus_params(PlutusVersion::V2, params)
+    }
+
+    pub fn from_plutus_v3_params(params: &[i64]) -> Result<Self, CostModelError> {
+        Self::from_plutus_params(PlutusVersion::V3, params)
+    }
+
+    /// Reads a cost model for scripts of `version` from JSON holding a
+    /// `builtinCostModel` and a `cekMachineCosts` object, in the format Plutus
+    /// publishes them in. Any builtin or step left out keeps its cost from
+    /// [`CostModel::for_version`].
+    pub fn from_json(version: PlutusVersion, json: &str) -> Result<Self, CostModelError> {
+        let value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| CostModelError::Json(e.to_string()))?;
+
+        let entries = value
+            .as_object()
+            .ok_or_else(|| CostModelError::Json("cost model must be an object".to_string()))?;
+
+        let mut cost_model = CostModel::for_version(version);
+
+        for (name, value) in entries {
+            match name.as_str() {
+                "builtinCostModel" => cost_model.builtin_costs.update_from_json(value)?,
+                "cekMachineCosts" => cost_model.machine_costs.update_from_json(value)?,
+                _ => return Err(CostModelError::UnknownParameter(name.clone())),
+            }
+        }
+
+        Ok(cost_model)
+    }
+
+    pub fn to_json(&self) -> String {
+        let value = serde_json::json!({
+            "builtinCostModel": self.builtin_costs.to_json(),
+            "cekMachineCosts": self.machine_costs.to_json(),
+        });
+
+        serde_json::to_string_pretty(&value).expect("cost model json is always serializable")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(usize)]
 pub enum StepKind {
     Constant = 0,
@@ -24,3 +126,260 @@ pub enum StepKind {
     Constr = 7,
     Case = 8,
 }
+
+impl StepKind {
+    pub const ALL: [StepKind; 9] = [
+        StepKind::Constant,
+        StepKind::Var,
+        StepKind::Lambda,
+        StepKind::Apply,
+        StepKind::Delay,
+        StepKind::Force,
+        StepKind::Builtin,
+        StepKind::Constr,
+        StepKind::Case,
+    ];
+}
+
+impl std::fmt::Display for StepKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            StepKind::Constant => "constant",
+            StepKind::Var => "var",
+            StepKind::Lambda => "lambda",
+            StepKind::Apply => "apply",
+            StepKind::Delay => "delay",
+            StepKind::Force => "force",
+            StepKind::Builtin => "builtin",
+            StepKind::Constr => "constr",
+            StepKind::Case => "case",
+        };
+
+        f.write_str(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::machine::ExBudget;
+
+    use super::{CostModel, CostModelError, PlutusVersion};
+
+    // Conway genesis, followed by the parameters added with the bitwise builtins
+    const PLUTUS_V3: [i64; 297] = [
+        100788, 420, 1, 1, 1000, 173, 0, 1, 1000, 59957, 4, 1, 11183, 32, 201305, 8356, 4, 16000,
+        100, 16000, 100, 16000, 100, 16000, 100, 16000, 100, 16000, 100, 100, 100, 16000, 100,
+        94375, 32, 132994, 32, 61462, 4, 72010, 178, 0, 1, 22151, 32, 91189, 769, 4, 2, 85848,
+        123203, 7305, -900, 1716, 549, 57, 85848, 0, 1, 1, 1000, 42921, 4, 2, 24548, 29498, 38, 1,
+        898148, 27279, 1, 51775, 558, 1, 39184, 1000, 60594, 1, 141895, 32, 83150, 32, 15299, 32,
+        76049, 1, 13169, 4, 22100, 10, 28999, 74, 1, 28999, 74, 1, 43285, 552, 1, 44749, 541, 1,
+        33852, 32, 68246, 32, 72362, 32, 7243, 32, 7391, 32, 11546, 32, 85848, 123203, 7305, -900,
+        1716, 549, 57, 85848, 0, 1, 90434, 519, 0, 1, 74433, 32, 85848, 123203, 7305, -900, 1716,
+        549, 57, 85848, 0, 1, 1, 85848, 123203, 7305, -900, 1716, 549, 57, 85848, 0, 1, 955506,
+        213312, 0, 2, 270652, 22588, 4, 1457325, 64566, 4, 20467, 1, 4, 0, 141992, 32, 100788, 420,
+        1, 1, 81663, 32, 59498, 32, 20142, 32, 24588, 32, 20744, 32, 25933, 32, 24623, 32,
+        43053543, 10, 53384111, 14333, 10, 43574283, 26308, 10, 16000, 100, 16000, 100, 962335, 18,
+        2780678, 6, 442008, 1, 52538055, 3756, 18, 267929, 18, 76433006, 8868, 18, 52948122, 18,
+        1995836, 36, 3227919, 12, 901022, 1, 166917843, 4307, 36, 284546, 36, 158221314, 26549, 36,
+        74698472, 36, 333849714, 1, 254006273, 72, 2174038, 72, 2261318, 64571, 4, 207616, 8310, 4,
+        1293828, 28716, 63, 0, 1, 1006041, 43623, 251, 0, 1, 100181, 726, 719, 0, 1, 100181, 726,
+        719, 0, 1, 100181, 726, 719, 0, 1, 107878, 680, 0, 1, 95336, 1, 281145, 18848, 0, 1,
+        180194, 159, 1, 1, 158519, 8942, 0, 1, 159378, 8813, 0, 1, 107490, 3298, 1, 106057, 655, 1,
+        1964219, 24520, 3,
+    ];
+
+    // Mainnet, V1 and V2 share the costs of their common builtins
+    const PLUTUS_V1: [i64; 166] = [
+        100788, 420, 1, 1, 1000, 173, 0, 1, 1000, 59957, 4, 1, 11183, 32, 201305, 8356, 4, 16000,
+        100, 16000, 100, 16000, 100, 16000, 100, 16000, 100, 16000, 100, 100, 100, 16000, 100,
+        94375, 32, 132994, 32, 61462, 4, 72010, 178, 0, 1, 22151, 32, 91189, 769, 4, 2, 85848,
+        228465, 122, 0, 1, 1, 1000, 42921, 4, 2, 24548, 29498, 38, 1, 898148, 27279, 1, 51775, 558,
+        1, 39184, 1000, 60594, 1, 141895, 32, 83150, 32, 15299, 32, 76049, 1, 13169, 4, 22100, 10,
+        28999, 74, 1, 28999, 74, 1, 43285, 552, 1, 44749, 541, 1, 33852, 32, 68246, 32, 72362, 32,
+        7243, 32, 7391, 32, 11546, 32, 85848, 228465, 122, 0, 1, 1, 90434, 519, 0, 1, 74433, 32,
+        85848, 228465, 122, 0, 1, 1, 85848, 228465, 122, 0, 1, 1, 270652, 22588, 4, 1457325, 64566,
+        4, 20467, 1, 4, 0, 141992, 32, 100788, 420, 1, 1, 81663, 32, 59498, 32, 20142, 32, 24588,
+        32, 20744, 32, 25933, 32, 24623, 32, 53384111, 14333, 10,
+    ];
+
+    const PLUTUS_V2: [i64; 175] = [
+        100788, 420, 1, 1, 1000, 173, 0, 1, 1000, 59957, 4, 1, 11183, 32, 201305, 8356, 4, 16000,
+        100, 16000, 100, 16000, 100, 16000, 100, 16000, 100, 16000, 100, 100, 100, 16000, 100,
+        94375, 32, 132994, 32, 61462, 4, 72010, 178, 0, 1, 22151, 32, 91189, 769, 4, 2, 85848,
+        228465, 122, 0, 1, 1, 1000, 42921, 4, 2, 24548, 29498, 38, 1, 898148, 27279, 1, 51775, 558,
+        1, 39184, 1000, 60594, 1, 141895, 32, 83150, 32, 15299, 32, 76049, 1, 13169, 4, 22100, 10,
+        28999, 74, 1, 28999, 74, 1, 43285, 552, 1, 44749, 541, 1, 33852, 32, 68246, 32, 72362, 32,
+        7243, 32, 7391, 32, 11546, 32, 85848, 228465, 122, 0, 1, 1, 90434, 519, 0, 1, 74433, 32,
+        85848, 228465, 122, 0, 1, 1, 85848, 228465, 122, 0, 1, 1, 955506, 213312, 0, 2, 270652,
+        22588, 4, 1457325, 64566, 4, 20467, 1, 4, 0, 141992, 32, 100788, 420, 1, 1, 81663, 32,
+        59498, 32, 20142, 32, 24588, 32, 20744, 32, 25933, 32, 24623, 32, 43053543, 10, 53384111,
+        14333, 10, 43574283, 26308, 10,
+    ];
+
+    #[test]
+    fn v3_params_match_default() {
+        assert_eq!(
+            CostModel::from_plutus_v3_params(&PLUTUS_V3).unwrap(),
+            CostModel::default()
+        );
+
+        assert_eq!(
+            CostModel::from_plutus_v3_params(&PLUTUS_V3[..251]).unwrap(),
+            CostModel::default()
+        );
+    }
+
+    #[test]
+    fn v1_and_v2_params_match_constructors() {
+        assert_eq!(
+            CostModel::from_plutus_v1_params(&PLUTUS_V1).unwrap(),
+            CostModel::v1()
+        );
+
+        assert_eq!(
+            CostModel::from_plutus_v2_params(&PLUTUS_V2).unwrap(),
+            CostModel::v2()
+        );
+
+        assert_ne!(CostModel::v2(), CostModel::v3());
+    }
+
+    #[test]
+    fn v2_division_is_costed_with_multiplied_sizes() {
+        let mut params = [0; 175];
+
+        // divideInteger-cpu-arguments-constant, -model-arguments-intercept, -slope
+        params[49..52].copy_from_slice(&[196500, 453240, 220]);
+        // divideInteger-memory-arguments-intercept, -minimum, -slope
+        params[52..55].copy_from_slice(&[0, 1, 1]);
+
+        let model = CostModel::from_plutus_v2_params(&params).unwrap();
+
+        assert_eq!(
+            model.builtin_costs.divide_integer([1, 2]),
+            ExBudget::new(1, 196500)
+        );
+        assert_eq!(
+            model.builtin_costs.divide_integer([3, 2]),
+            ExBudget::new(1, 453240 + 220 * 6)
+        );
+    }
+
+    #[test]
+    fn wrong_length() {
+        let err = CostModel::from_plutus_v1_params(&[0; 175]).unwrap_err();
+
+        assert_eq!(
+            err,
+            CostModelError::WrongLength {
+                version: PlutusVersion::V1,
+                expected: &[166],
+                actual: 175,
+            }
+        );
+
+        assert_eq!(
+            CostModel::from_plutus_v2_params(&[])
+                .unwrap_err()
+                .to_string(),
+            "V2 cost model takes 175 or 185 parameters, found 0"
+        );
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let json = CostModel::default().to_json();
+
+        assert_eq!(
+            CostModel::from_json(PlutusVersion::V3, &json).unwrap(),
+            CostModel::default()
+        );
+    }
+
+    #[test]
+    fn json_overrides_named_builtins() {
+        let json = r#"{
+            "builtinCostModel": {
+                "divideInteger": {
+                    "cpu": {
+                        "arguments": {
+                            "constant": 196500,
+                            "model": {
+                                "arguments": { "intercept": 453240, "slope": 220 },
+                                "type": "multiplied_sizes"
+                            }
+                        },
+                        "type": "const_above_diagonal"
+                    },
+                    "memory": {
+                        "arguments": { "intercept": 0, "minimum": 1, "slope": 1 },
+                        "type": "subtracted_sizes"
+                    }
+                }
+            },
+            "cekMachineCosts": {
+                "cekVarCost": { "exBudgetCPU": 23000, "exBudgetMemory": 100 }
+            }
+        }"#;
+
+        let model = CostModel::from_json(PlutusVersion::V3, json).unwrap();
+
+        assert_eq!(
+            model.builtin_costs.divide_integer([3, 2]),
+            ExBudget::new(1, 453240 + 220 * 6)
+        );
+        assert_eq!(model.machine_costs.get(1), ExBudget::new(100, 23000));
+        assert_eq!(
+            model.builtin_costs.add_integer([1, 1]),
+            CostModel::default().builtin_costs.add_integer([1, 1])
+        );
+    }
+
+    #[test]
+    fn json_defaults_to_the_version_cost_model() {
+        let json = r#"{
+            "cekMachineCosts": {
+                "cekVarCost": { "exBudgetCPU": 23000, "exBudgetMemory": 100 }
+            }
+        }"#;
+
+        let model = CostModel::from_json(PlutusVersion::V1, json).unwrap();
+
+        assert_eq!(model.builtin_costs, CostModel::v1().builtin_costs);
+        assert_eq!(model.machine_costs.get(1), ExBudget::new(100, 23000));
+
+        let json = CostModel::v1().to_json();
+
+        assert_eq!(
+            CostModel::from_json(PlutusVersion::V1, &json).unwrap(),
+            CostModel::v1()
+        );
+    }
+
+    #[test]
+    fn json_errors() {
+        assert_eq!(
+            CostModel::from_json(
+                PlutusVersion::V3,
+                r#"{ "builtinCostModel": { "addInteger2": {} } }"#
+            ),
+            Err(CostModelError::UnknownParameter("addInteger2".to_string()))
+        );
+
+        let err = CostModel::from_json(
+            PlutusVersion::V3,
+            r#"{ "builtinCostModel": { "addInteger": {
+                "cpu": { "arguments": 1, "type": "cubic" },
+                "memory": { "arguments": 1, "type": "constant_cost" }
+            } } }"#,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "invalid cost model for addInteger: unknown model type cubic"
+        );
+    }
+}
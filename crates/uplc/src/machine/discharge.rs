@@ -18,7 +18,7 @@ where
                 term = term.force(arena);
             }
 
-            for arg in &runtime.args {
+            for arg in runtime.args.iter() {
                 term = term.apply(arena, value_as_term(arena, arg));
             }
 
@@ -1,31 +1,152 @@
-use bumpalo::{collections::Vec as BumpVec, Bump};
+use bumpalo::Bump;
 
 use crate::binder::Eval;
 
 use super::value::Value;
 
+/// A skew binary random access list, pushing shares the existing spine so it
+/// is O(1) and a lookup walks at most O(log n) nodes.
 #[derive(Debug)]
-pub struct Env<'a, V>(BumpVec<'a, &'a Value<'a, V>>)
+pub enum Env<'a, V>
 where
-    V: Eval<'a>;
+    V: Eval<'a>,
+{
+    Empty,
+    Cons {
+        size: usize,
+        tree: &'a Tree<'a, V>,
+        rest: &'a Env<'a, V>,
+    },
+}
+
+/// A complete binary tree holding the most recently pushed value at its root.
+#[derive(Debug)]
+pub enum Tree<'a, V>
+where
+    V: Eval<'a>,
+{
+    Leaf(&'a Value<'a, V>),
+    Node(&'a Value<'a, V>, &'a Tree<'a, V>, &'a Tree<'a, V>),
+}
 
 impl<'a, V> Env<'a, V>
 where
     V: Eval<'a>,
 {
     pub fn new_in(arena: &'a Bump) -> &'a Self {
-        arena.alloc(Self(BumpVec::new_in(arena)))
+        arena.alloc(Env::Empty)
     }
 
     pub fn push(&'a self, arena: &'a Bump, argument: &'a Value<'a, V>) -> &'a Self {
-        let mut new_env = self.0.clone();
-
-        new_env.push(argument);
+        if let Env::Cons {
+            size,
+            tree: left,
+            rest:
+                Env::Cons {
+                    size: next_size,
+                    tree: right,
+                    rest,
+                },
+        } = self
+        {
+            if size == next_size {
+                return arena.alloc(Env::Cons {
+                    size: 1 + size + next_size,
+                    tree: arena.alloc(Tree::Node(argument, left, right)),
+                    rest,
+                });
+            }
+        }
 
-        arena.alloc(Self(new_env))
+        arena.alloc(Env::Cons {
+            size: 1,
+            tree: arena.alloc(Tree::Leaf(argument)),
+            rest: self,
+        })
     }
 
+    /// Looks up a de Bruijn index, 1 being the most recently pushed value.
     pub fn lookup(&'a self, name: usize) -> Option<&'a Value<'a, V>> {
-        self.0.get(self.0.len() - name).copied()
+        let mut index = name.checked_sub(1)?;
+        let mut env = self;
+
+        while let Env::Cons { size, tree, rest } = env {
+            if index < *size {
+                return Some(tree.lookup(*size, index));
+            }
+
+            index -= size;
+            env = rest;
+        }
+
+        None
+    }
+
+    /// Every value in the environment, most recently pushed first.
+    pub fn iter(&'a self) -> impl Iterator<Item = &'a Value<'a, V>> {
+        (1..).map_while(|name| self.lookup(name))
+    }
+}
+
+impl<'a, V> Tree<'a, V>
+where
+    V: Eval<'a>,
+{
+    fn lookup(&'a self, mut size: usize, mut index: usize) -> &'a Value<'a, V> {
+        let mut tree = self;
+
+        loop {
+            match tree {
+                Tree::Leaf(value) => return value,
+                Tree::Node(value, left, right) => {
+                    if index == 0 {
+                        return value;
+                    }
+
+                    size /= 2;
+
+                    if index <= size {
+                        index -= 1;
+                        tree = left;
+                    } else {
+                        index -= 1 + size;
+                        tree = right;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bumpalo::Bump;
+
+    use crate::{binder::DeBruijn, constant::Constant, machine::value::Value};
+
+    use super::Env;
+
+    #[test]
+    fn lookup_finds_every_pushed_value() {
+        let arena = Bump::new();
+
+        let mut env: &Env<DeBruijn> = Env::new_in(&arena);
+
+        for count in 0..100 {
+            for name in 1..=count {
+                let value = env.lookup(name).unwrap();
+
+                let expected = Constant::integer_from(&arena, (count - name) as i128);
+
+                assert!(matches!(value, Value::Con(constant) if *constant == expected));
+            }
+
+            assert!(env.lookup(0).is_none());
+            assert!(env.lookup(count + 1).is_none());
+
+            let value = Value::con(&arena, Constant::integer_from(&arena, count as i128));
+
+            env = env.push(&arena, value);
+        }
     }
 }
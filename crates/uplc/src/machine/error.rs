@@ -3,6 +3,7 @@ use std::array::TryFromSliceError;
 use crate::{
     binder::Eval,
     bls::BlsError,
+    builtin::UnavailableBuiltin,
     constant::{Constant, Integer},
     data::PlutusData,
     term::Term,
@@ -42,6 +43,10 @@ where
     Runtime(RuntimeError<'a>),
     #[error("Max constr tag exceeded")]
     MaxConstrTagExceeded(&'a Value<'a, V>),
+    #[error(transparent)]
+    UnavailableBuiltin(UnavailableBuiltin),
+    #[error("Constr and case are not supported before 1.1.0")]
+    UnsupportedTerm(&'a Term<'a, V>),
 }
 
 #[derive(thiserror::Error, Debug)]
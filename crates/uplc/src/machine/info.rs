@@ -1,7 +1,9 @@
-use super::ExBudget;
+use super::{tally::BudgetTally, ExBudget};
 
 #[derive(Debug)]
 pub struct MachineInfo {
     pub consumed_budget: ExBudget,
     pub logs: Vec<String>,
+    /// Set when evaluating with [`EvalOptions::tally`](crate::program::EvalOptions::tally).
+    pub tally: Option<BudgetTally>,
 }
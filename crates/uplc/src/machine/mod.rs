@@ -1,3 +1,4 @@
+mod batch;
 mod cek;
 mod context;
 mod cost_model;
@@ -6,15 +7,31 @@ mod env;
 mod error;
 mod eval_result;
 mod info;
+mod observer;
+mod profile;
 mod runtime;
 mod state;
+mod tally;
 mod value;
 
+pub use batch::*;
 pub use cek::*;
+pub use context::Context;
 pub use cost_model::ex_budget::*;
-pub use cost_model::CostModel;
+pub use cost_model::{CostModel, CostModelError, StepKind};
+pub use env::Env;
 pub use error::*;
 pub use eval_result::*;
 pub use info::*;
+pub use observer::MachineObserver;
+pub use profile::{Profile, ProfileBinder};
+pub use runtime::Args;
 pub use runtime::BuiltinSemantics;
 pub use runtime::PlutusVersion;
+pub use runtime::ProtocolVersion;
+pub use runtime::Runtime;
+pub use state::MachineState;
+pub use tally::{BudgetTally, Tallied};
+pub use value::Value;
+
+pub(crate) use tally::Tallying;
@@ -0,0 +1,122 @@
+use crate::{binder::Eval, builtin::DefaultFunction};
+
+use super::{context::Context, cost_model::StepKind, runtime::Args, value::Value, ExBudget};
+
+/// Hooks into evaluation for profilers, coverage and custom meters. Every
+/// method does nothing by default, and as the machine is generic over its
+/// observer the default `()` costs nothing.
+pub trait MachineObserver<'a, V>
+where
+    V: Eval<'a>,
+{
+    /// A step was taken with `context` as its continuation. Steps are
+    /// charged in batches, so their cost shows up later in
+    /// [`MachineObserver::steps_spent`].
+    fn step(&mut self, _kind: StepKind, _context: &'a Context<'a, V>) {}
+
+    /// The steps of `kind` taken since the last batch were charged `budget`,
+    /// right before it is taken in [`MachineObserver::budget_spent`].
+    fn steps_spent(&mut self, _kind: StepKind, _budget: ExBudget) {}
+
+    /// The lambda `function` was applied, its body runs with `context` as
+    /// its continuation.
+    fn lambda_applied(&mut self, _function: &'a Value<'a, V>, _context: &'a Context<'a, V>) {}
+
+    /// `value` was returned to `context`.
+    fn returned(&mut self, _context: &'a Context<'a, V>, _value: &'a Value<'a, V>) {}
+
+    /// A saturated builtin was called, `cost` is what the call spent and is
+    /// reported even when the call fails.
+    fn builtin_call(&mut self, _fun: DefaultFunction, _args: &Args<'a, V>, _cost: ExBudget) {}
+
+    /// `budget` was taken from the remaining budget.
+    fn budget_spent(&mut self, _budget: ExBudget) {}
+
+    /// A message was traced.
+    fn trace(&mut self, _message: &str) {}
+}
+
+impl<'a, V> MachineObserver<'a, V> for () where V: Eval<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use bumpalo::Bump;
+
+    use crate::{
+        binder::{DeBruijn, Eval},
+        builtin::DefaultFunction,
+        machine::{runtime::Args, Context, ExBudget, StepKind},
+        program::{EvalOptions, Program},
+        syn,
+    };
+
+    use super::MachineObserver;
+
+    #[derive(Default)]
+    struct Recorder {
+        steps: usize,
+        applies: usize,
+        calls: Vec<(DefaultFunction, usize)>,
+        cpu: i64,
+        mem: i64,
+        traces: Vec<String>,
+    }
+
+    impl<'a, V> MachineObserver<'a, V> for Recorder
+    where
+        V: Eval<'a>,
+    {
+        fn step(&mut self, kind: StepKind, _context: &'a Context<'a, V>) {
+            self.steps += 1;
+
+            if kind == StepKind::Apply {
+                self.applies += 1;
+            }
+        }
+
+        fn builtin_call(&mut self, fun: DefaultFunction, args: &Args<'a, V>, _: ExBudget) {
+            self.calls.push((fun, args.len()));
+        }
+
+        fn budget_spent(&mut self, budget: ExBudget) {
+            self.cpu += budget.cpu;
+            self.mem += budget.mem;
+        }
+
+        fn trace(&mut self, message: &str) {
+            self.traces.push(message.to_string());
+        }
+    }
+
+    #[test]
+    fn observer_sees_evaluation() {
+        let arena = Bump::new();
+
+        let program: &Program<DeBruijn> = syn::parse_program(
+            &arena,
+            r#"(program 1.1.0 [(force (builtin trace)) (con string "hi") [(builtin addInteger) (con integer 1) (con integer 2)]])"#,
+        )
+        .into_result()
+        .unwrap();
+
+        let options = EvalOptions::default();
+
+        let (result, recorder) = program.eval_with_observer(&arena, &options, Recorder::default());
+
+        assert!(result.term.is_ok());
+        assert_eq!(recorder.steps, 10);
+        assert_eq!(recorder.applies, 4);
+        assert_eq!(
+            recorder.calls,
+            [
+                (DefaultFunction::AddInteger, 2),
+                (DefaultFunction::Trace, 2)
+            ]
+        );
+        assert_eq!(
+            ExBudget::new(recorder.mem, recorder.cpu),
+            result.info.consumed_budget
+        );
+        assert_eq!(recorder.traces, ["hi"]);
+    }
+}
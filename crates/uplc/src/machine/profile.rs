@@ -0,0 +1,561 @@
+use std::collections::BTreeMap;
+
+use bumpalo::{collections::Vec as BumpVec, Bump};
+
+use crate::{
+    binder::{ConvertError, DeBruijn, Eval, Name, NamedDeBruijn},
+    builtin::DefaultFunction,
+    program::{EvalOptions, Program},
+    term::Term,
+};
+
+use super::{
+    cost_model::{MachineCosts, StepKind},
+    observer::MachineObserver,
+    runtime::Args,
+    Context, EvalResult, ExBudget, Value,
+};
+
+/// The bottom of every stack, renamed by a trace outside of any function.
+const ROOT: &str = "program";
+
+/// Budget spent per call stack, written in the folded format flamegraph
+/// tools read, one `program;outer;inner 1234` line per stack.
+#[derive(Debug, Default)]
+pub struct Profile {
+    stacks: BTreeMap<String, ExBudget>,
+}
+
+impl Profile {
+    pub fn stacks(&self) -> impl Iterator<Item = (&str, ExBudget)> {
+        self.stacks
+            .iter()
+            .map(|(stack, budget)| (stack.as_str(), *budget))
+    }
+
+    pub fn folded_cpu(&self) -> String {
+        self.folded(|budget| budget.cpu)
+    }
+
+    pub fn folded_mem(&self) -> String {
+        self.folded(|budget| budget.mem)
+    }
+
+    fn folded(&self, weight: impl Fn(&ExBudget) -> i64) -> String {
+        self.stacks
+            .iter()
+            .filter(|(_, budget)| weight(budget) > 0)
+            .map(|(stack, budget)| format!("{stack} {}\n", weight(budget)))
+            .collect()
+    }
+
+    fn add(&mut self, stack: &str, budget: ExBudget) {
+        if budget.cpu == 0 && budget.mem == 0 {
+            return;
+        }
+
+        match self.stacks.get_mut(stack) {
+            Some(total) => *total = *total + budget,
+            None => {
+                self.stacks.insert(stack.to_string(), budget);
+            }
+        }
+    }
+}
+
+/// Binders a profile can take function names from.
+pub trait ProfileBinder<'a>: Eval<'a> {
+    /// The name the binder was written with, if it has one.
+    fn name(&self) -> Option<&'a str>;
+}
+
+impl<'a> ProfileBinder<'a> for DeBruijn {
+    fn name(&self) -> Option<&'a str> {
+        None
+    }
+}
+
+impl<'a> ProfileBinder<'a> for NamedDeBruijn<'a> {
+    fn name(&self) -> Option<&'a str> {
+        Some(self.text())
+    }
+}
+
+impl<'a, V> Program<'a, V>
+where
+    V: ProfileBinder<'a>,
+{
+    /// Evaluates the program, charging every step to the stack of calls in
+    /// progress when it was taken, and builtin calls to a frame of their own
+    /// on top of it. A call lasts until its body returns, lambdas applied
+    /// where they are written are lets and get no frame. Steps the machine
+    /// never charged are left out, so the stacks add up to the consumed
+    /// budget even when evaluation fails.
+    ///
+    /// Calls are named after the lambda called, which is named after what
+    /// it is bound to by `[(lam f ..) (lam ..)]` or else its own binder, and
+    /// numbered `lam_0`, `lam_1`, ... in the order they appear when there
+    /// is no name to go by. The program evaluated is a copy with its lambdas
+    /// renamed to match, so a returned function is printed with these names.
+    pub fn profile(
+        &'a self,
+        arena: &'a Bump,
+        options: EvalOptions,
+    ) -> (EvalResult<'a, NamedDeBruijn<'a>>, Profile) {
+        let program = Program::new(arena, self.version, label(arena, self.term, None, &mut 0));
+
+        let options = arena.alloc(options);
+
+        let profiler = Profiler::new(options.cost_model.machine_costs.clone());
+
+        let (result, profiler) = program.eval_with_observer(arena, options, profiler);
+
+        (result, profiler.into_profile())
+    }
+}
+
+impl<'a> Program<'a, Name<'a>> {
+    /// Like [`Program::profile`], for a program that has yet to be converted.
+    pub fn profile(
+        &'a self,
+        arena: &'a Bump,
+        options: EvalOptions,
+    ) -> Result<(EvalResult<'a, NamedDeBruijn<'a>>, Profile), ConvertError> {
+        Ok(self.to_named_debruijn(arena)?.profile(arena, options))
+    }
+}
+
+/// Copies `term` naming every lambda for the profile, the lambdas a curried
+/// function returns keep its name.
+fn label<'a, V>(
+    arena: &'a Bump,
+    term: &'a Term<'a, V>,
+    name: Option<&'a str>,
+    lambdas: &mut usize,
+) -> &'a Term<'a, NamedDeBruijn<'a>>
+where
+    V: ProfileBinder<'a>,
+{
+    match term {
+        Term::Var(var) => Term::var(
+            arena,
+            NamedDeBruijn::new(arena, var.name().unwrap_or("i"), var.index()),
+        ),
+        Term::Lambda { parameter, body } => {
+            let id = *lambdas;
+
+            *lambdas += 1;
+
+            let name = name
+                .or_else(|| parameter.name())
+                .unwrap_or_else(|| arena.alloc_str(&format!("lam_{id}")));
+
+            let inherited = matches!(body, Term::Lambda { .. }).then_some(name);
+
+            label(arena, body, inherited, lambdas).lambda(arena, NamedDeBruijn::new(arena, name, 0))
+        }
+        Term::Apply { function, argument } => {
+            let bound = match (function, argument) {
+                (Term::Lambda { parameter, .. }, Term::Lambda { .. }) => parameter.name(),
+                _ => None,
+            };
+
+            let function = label(arena, function, None, lambdas);
+            let argument = label(arena, argument, bound, lambdas);
+
+            function.apply(arena, argument)
+        }
+        Term::Delay(body) => label(arena, body, None, lambdas).delay(arena),
+        Term::Force(body) => label(arena, body, None, lambdas).force(arena),
+        Term::Case { constr, branches } => {
+            let constr = label(arena, constr, None, lambdas);
+            let branches = label_all(arena, branches, lambdas);
+
+            Term::case(arena, constr, branches)
+        }
+        Term::Constr { tag, fields } => {
+            let fields = label_all(arena, fields, lambdas);
+
+            Term::constr(arena, *tag, fields)
+        }
+        Term::Constant(constant) => Term::constant(arena, constant),
+        Term::Builtin(fun) => arena.alloc(Term::Builtin(fun)),
+        Term::Error => Term::error(arena),
+    }
+}
+
+fn label_all<'a, V>(
+    arena: &'a Bump,
+    terms: &'a [&'a Term<'a, V>],
+    lambdas: &mut usize,
+) -> &'a [&'a Term<'a, NamedDeBruijn<'a>>]
+where
+    V: ProfileBinder<'a>,
+{
+    let mut labelled = BumpVec::with_capacity_in(terms.len(), arena);
+
+    for term in terms {
+        labelled.push(label(arena, term, None, lambdas));
+    }
+
+    labelled.into_bump_slice()
+}
+
+/// A call stack, its frame on top of its parent's.
+struct Stack {
+    name: String,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    budget: ExBudget,
+}
+
+/// A call in progress, over once a value is returned to its continuation.
+struct Frame<'a> {
+    context: &'a Context<'a, NamedDeBruijn<'a>>,
+    stack: usize,
+}
+
+/// Observer behind [`Program::profile`], pushing a frame for every call and
+/// popping it when the call returns.
+struct Profiler<'a> {
+    machine_costs: MachineCosts,
+    /// Every stack seen so far, indexed by the frames and steps on it.
+    stacks: Vec<Stack>,
+    root: usize,
+    frames: Vec<Frame<'a>>,
+    /// Continuations of the lambdas about to be applied where they are
+    /// written, `[(lam x ..) ..]` or a case branch. Those are lets rather
+    /// than calls, so they get no frame.
+    lets: Vec<&'a Context<'a, NamedDeBruijn<'a>>>,
+    /// The stacks of the steps not charged yet, per step kind.
+    unspent: [Vec<usize>; 9],
+}
+
+impl<'a> Profiler<'a> {
+    fn new(machine_costs: MachineCosts) -> Self {
+        let root = Stack {
+            name: ROOT.to_string(),
+            parent: None,
+            children: Vec::new(),
+            budget: machine_costs.startup(),
+        };
+
+        Profiler {
+            machine_costs,
+            stacks: vec![root],
+            root: 0,
+            frames: Vec::new(),
+            lets: Vec::new(),
+            unspent: Default::default(),
+        }
+    }
+
+    fn stack(&self) -> usize {
+        self.frames.last().map_or(self.root, |frame| frame.stack)
+    }
+
+    /// The stack `name` makes on top of `parent`, or at the bottom.
+    fn push(&mut self, parent: Option<usize>, name: &str) -> usize {
+        let found = match parent {
+            Some(parent) => self.stacks[parent]
+                .children
+                .iter()
+                .copied()
+                .find(|child| self.stacks[*child].name == name),
+            None => self
+                .stacks
+                .iter()
+                .position(|stack| stack.parent.is_none() && stack.name == name),
+        };
+
+        if let Some(stack) = found {
+            return stack;
+        }
+
+        let stack = self.stacks.len();
+
+        self.stacks.push(Stack {
+            name: name.to_string(),
+            parent,
+            children: Vec::new(),
+            budget: ExBudget::new(0, 0),
+        });
+
+        if let Some(parent) = parent {
+            self.stacks[parent].children.push(stack);
+        }
+
+        stack
+    }
+
+    fn into_profile(self) -> Profile {
+        let mut profile = Profile::default();
+
+        for stack in &self.stacks {
+            let mut names = vec![stack.name.as_str()];
+            let mut parent = stack.parent;
+
+            while let Some(next) = parent {
+                names.push(&self.stacks[next].name);
+                parent = self.stacks[next].parent;
+            }
+
+            names.reverse();
+
+            profile.add(&names.join(";"), stack.budget);
+        }
+
+        profile
+    }
+}
+
+impl<'a> MachineObserver<'a, NamedDeBruijn<'a>> for Profiler<'a> {
+    fn step(&mut self, kind: StepKind, context: &'a Context<'a, NamedDeBruijn<'a>>) {
+        // a lambda a call returns to its continuation is curried, not a let
+        if kind == StepKind::Lambda
+            && !self
+                .frames
+                .last()
+                .is_some_and(|frame| std::ptr::eq(frame.context, context))
+        {
+            if let Context::FrameAwaitFunTerm(_, _, next) | Context::FrameAwaitFunValue(_, next) =
+                context
+            {
+                self.lets.push(next);
+            }
+        }
+
+        let stack = self.stack();
+
+        self.unspent[kind as usize].push(stack);
+    }
+
+    fn steps_spent(&mut self, kind: StepKind, _budget: ExBudget) {
+        let cost = self.machine_costs.get(kind as usize);
+
+        for stack in self.unspent[kind as usize].drain(..) {
+            self.stacks[stack].budget = self.stacks[stack].budget + cost;
+        }
+    }
+
+    fn lambda_applied(
+        &mut self,
+        function: &'a Value<'a, NamedDeBruijn<'a>>,
+        context: &'a Context<'a, NamedDeBruijn<'a>>,
+    ) {
+        if self
+            .lets
+            .last()
+            .is_some_and(|next| std::ptr::eq(*next, context))
+        {
+            self.lets.pop();
+
+            return;
+        }
+
+        if let Value::Lambda { parameter, .. } = function {
+            let stack = self.push(Some(self.stack()), parameter.text());
+
+            self.frames.push(Frame { context, stack });
+        }
+    }
+
+    fn returned(
+        &mut self,
+        context: &'a Context<'a, NamedDeBruijn<'a>>,
+        _value: &'a Value<'a, NamedDeBruijn<'a>>,
+    ) {
+        while self
+            .frames
+            .last()
+            .is_some_and(|frame| std::ptr::eq(frame.context, context))
+        {
+            self.frames.pop();
+        }
+    }
+
+    fn builtin_call(
+        &mut self,
+        fun: DefaultFunction,
+        _args: &Args<'a, NamedDeBruijn<'a>>,
+        cost: ExBudget,
+    ) {
+        let stack = self.push(Some(self.stack()), &fun.to_string());
+
+        self.stacks[stack].budget = self.stacks[stack].budget + cost;
+    }
+
+    // renames the innermost call, or the root outside of any
+    fn trace(&mut self, message: &str) {
+        let label = message.replace(';', ":").replace('\n', " ");
+
+        match self.frames.last().map(|frame| frame.stack) {
+            Some(traced) => {
+                let stack = self.push(self.stacks[traced].parent, &label);
+
+                if let Some(frame) = self.frames.last_mut() {
+                    frame.stack = stack;
+                }
+            }
+            None => self.root = self.push(None, &label),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bumpalo::Bump;
+
+    use crate::{
+        binder::{DeBruijn, Name},
+        flat,
+        machine::ExBudget,
+        program::{EvalOptions, Program},
+        syn,
+    };
+
+    const DOUBLE: &str = "(program 1.1.0
+      [(lam double
+        [(lam quadruple [quadruple (con integer 1)])
+         (lam y [double [double y]])])
+       (lam x [(builtin addInteger) x x])])";
+
+    #[test]
+    fn profile_stacks_named_calls() {
+        let arena = Bump::new();
+
+        let program: &Program<Name> = syn::parse_named_program(&arena, DOUBLE)
+            .into_result()
+            .unwrap();
+
+        let (result, profile) = program.profile(&arena, EvalOptions::default()).unwrap();
+
+        assert_eq!(result.term.unwrap().to_pretty(), "(con integer 4)");
+
+        let stacks: Vec<_> = profile.stacks().map(|(stack, _)| stack).collect();
+
+        assert_eq!(
+            stacks,
+            [
+                "program",
+                "program;quadruple",
+                "program;quadruple;double",
+                "program;quadruple;double;addInteger",
+            ]
+        );
+
+        let total = profile
+            .stacks()
+            .fold(ExBudget::new(0, 0), |total, (_, budget)| total + budget);
+
+        assert_eq!(total, result.info.consumed_budget);
+
+        assert!(profile.folded_cpu().lines().all(|line| line
+            .rsplit_once(' ')
+            .unwrap()
+            .1
+            .parse::<i64>()
+            .is_ok()));
+    }
+
+    #[test]
+    fn profile_numbers_decoded_lambdas() {
+        let arena = Bump::new();
+
+        let program: &Program<DeBruijn> = syn::parse_program(&arena, DOUBLE).into_result().unwrap();
+
+        let program = flat::decode::<DeBruijn>(&arena, &flat::encode(program).unwrap()).unwrap();
+
+        let (result, profile) = program.profile(&arena, EvalOptions::default());
+
+        let stacks: Vec<_> = profile.stacks().map(|(stack, _)| stack).collect();
+
+        assert_eq!(
+            stacks,
+            [
+                "program",
+                "program;lam_2",
+                "program;lam_2;lam_3",
+                "program;lam_2;lam_3;addInteger",
+            ]
+        );
+
+        let total = profile
+            .stacks()
+            .fold(ExBudget::new(0, 0), |total, (_, budget)| total + budget);
+
+        assert_eq!(total, result.info.consumed_budget);
+    }
+
+    #[test]
+    fn profile_adds_up_to_consumed_budget() {
+        let arena = Bump::new();
+
+        let failing: &Program<Name> = syn::parse_named_program(
+            &arena,
+            "(program 1.1.0 [(lam x [(builtin addInteger) x (error)]) (con integer 1)])",
+        )
+        .into_result()
+        .unwrap();
+
+        let program: &Program<Name> = syn::parse_named_program(&arena, DOUBLE)
+            .into_result()
+            .unwrap();
+
+        let runs = [
+            (failing, EvalOptions::default(), false),
+            (program, EvalOptions::default(), true),
+            (
+                program,
+                EvalOptions {
+                    budget: ExBudget::new(10_000, 200_000),
+                    ..Default::default()
+                },
+                false,
+            ),
+        ];
+
+        for (program, options, succeeds) in runs {
+            let (result, profile) = program.profile(&arena, options).unwrap();
+
+            assert_eq!(result.term.is_ok(), succeeds);
+
+            let sum = |folded: String| -> i64 {
+                folded
+                    .lines()
+                    .map(|line| line.rsplit_once(' ').unwrap().1.parse::<i64>().unwrap())
+                    .sum()
+            };
+
+            let consumed = result.info.consumed_budget;
+
+            assert_eq!(sum(profile.folded_cpu()), consumed.cpu);
+            assert_eq!(sum(profile.folded_mem()), consumed.mem);
+        }
+    }
+
+    #[test]
+    fn profile_names_frames_by_trace() {
+        let arena = Bump::new();
+
+        let program: &Program<Name> = syn::parse_named_program(
+            &arena,
+            r#"(program 1.1.0
+              [(lam check [check (con integer 1)])
+               (lam x
+                 [(builtin addInteger)
+                  [(force (builtin trace)) (con string "checking") x]
+                  x])])"#,
+        )
+        .into_result()
+        .unwrap();
+
+        let (_, profile) = program.profile(&arena, EvalOptions::default()).unwrap();
+
+        let stacks: Vec<_> = profile.stacks().map(|(stack, _)| stack).collect();
+
+        assert!(stacks.contains(&"program;check"));
+        assert!(stacks.contains(&"program;checking;trace"));
+        assert!(stacks.contains(&"program;checking;addInteger"));
+    }
+}
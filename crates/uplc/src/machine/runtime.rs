@@ -15,7 +15,7 @@ use bumpalo::{
 };
 use num::{Integer as NumInteger, Signed, Zero};
 
-use super::{cost_model, value::Value, Machine, MachineError};
+use super::{cost_model, observer::MachineObserver, value::Value, Machine, MachineError};
 
 pub const INTEGER_TO_BYTE_STRING_MAXIMUM_OUTPUT_LENGTH: i64 = 8192;
 
@@ -24,12 +24,52 @@ pub enum BuiltinSemantics {
     V2,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PlutusVersion {
     V1,
     V2,
     V3,
 }
 
+impl PlutusVersion {
+    /// The byte the ledger prefixes a script with before hashing it.
+    pub fn language_tag(&self) -> u8 {
+        match self {
+            PlutusVersion::V1 => 1,
+            PlutusVersion::V2 => 2,
+            PlutusVersion::V3 => 3,
+        }
+    }
+}
+
+/// Major version of the ledger protocol, which decides the builtins each
+/// Plutus version may use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ProtocolVersion(pub u64);
+
+impl ProtocolVersion {
+    pub const ALONZO: ProtocolVersion = ProtocolVersion(5);
+    pub const VASIL: ProtocolVersion = ProtocolVersion(7);
+    pub const VALENTINE: ProtocolVersion = ProtocolVersion(8);
+    pub const CHANG: ProtocolVersion = ProtocolVersion(9);
+    pub const PLOMIN: ProtocolVersion = ProtocolVersion(10);
+    /// Enables every builtin the machine implements, for every Plutus
+    /// version, and is the default.
+    pub const PV11: ProtocolVersion = ProtocolVersion(11);
+}
+
+impl Default for ProtocolVersion {
+    fn default() -> Self {
+        ProtocolVersion::PV11
+    }
+}
+
+impl std::fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
 impl From<&PlutusVersion> for BuiltinSemantics {
     fn from(version: &PlutusVersion) -> Self {
         match version {
@@ -40,12 +80,100 @@ impl From<&PlutusVersion> for BuiltinSemantics {
     }
 }
 
+/// The most arguments any builtin takes, `chooseData` has six.
+pub const MAX_ARITY: usize = 6;
+
+/// Arguments a builtin has been applied to so far, kept inline so partially
+/// applying a builtin never clones a vector.
+pub struct Args<'a, V>
+where
+    V: Eval<'a>,
+{
+    values: [Option<&'a Value<'a, V>>; MAX_ARITY],
+    len: usize,
+}
+
+impl<'a, V> Args<'a, V>
+where
+    V: Eval<'a>,
+{
+    pub fn new() -> Self {
+        Self {
+            values: [None; MAX_ARITY],
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a Value<'a, V>> + '_ {
+        self.values[..self.len].iter().flatten().copied()
+    }
+
+    fn push(&self, arg: &'a Value<'a, V>) -> Self {
+        let mut args = *self;
+
+        args.values[args.len] = Some(arg);
+        args.len += 1;
+
+        args
+    }
+}
+
+impl<'a, V> Default for Args<'a, V>
+where
+    V: Eval<'a>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, V> Clone for Args<'a, V>
+where
+    V: Eval<'a>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, V> Copy for Args<'a, V> where V: Eval<'a> {}
+
+impl<'a, V> std::ops::Index<usize> for Args<'a, V>
+where
+    V: Eval<'a>,
+{
+    type Output = &'a Value<'a, V>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.values[..self.len][index]
+            .as_ref()
+            .expect("builtin arguments are contiguous")
+    }
+}
+
+impl<'a, V> std::fmt::Debug for Args<'a, V>
+where
+    V: Eval<'a>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
 #[derive(Debug)]
 pub struct Runtime<'a, V>
 where
     V: Eval<'a>,
 {
-    pub args: BumpVec<'a, &'a Value<'a, V>>,
+    pub args: Args<'a, V>,
     pub fun: &'a DefaultFunction,
     pub forces: usize,
 }
@@ -56,32 +184,26 @@ where
 {
     pub fn new(arena: &'a Bump, fun: &'a DefaultFunction) -> &'a Self {
         arena.alloc(Self {
-            args: BumpVec::new_in(arena),
+            args: Args::new(),
             fun,
             forces: 0,
         })
     }
 
     pub fn force(&self, arena: &'a Bump) -> &'a Self {
-        let new_runtime = arena.alloc(Runtime {
-            args: self.args.clone(),
+        arena.alloc(Runtime {
+            args: self.args,
             fun: self.fun,
             forces: self.forces + 1,
-        });
-
-        new_runtime
+        })
     }
 
     pub fn push(&self, arena: &'a Bump, arg: &'a Value<'a, V>) -> &'a Self {
-        let new_runtime = arena.alloc(Runtime {
-            args: self.args.clone(),
+        arena.alloc(Runtime {
+            args: self.args.push(arg),
             fun: self.fun,
             forces: self.forces,
-        });
-
-        new_runtime.args.push(arg);
-
-        new_runtime
+        })
     }
 
     pub fn needs_force(&self) -> bool {
@@ -97,13 +219,14 @@ where
     }
 }
 
-impl<'a> Machine<'a> {
+impl<'a, O> Machine<'a, O> {
     pub fn call<V>(
         &mut self,
         runtime: &'a Runtime<'a, V>,
     ) -> Result<&'a Value<'a, V>, MachineError<'a, V>>
     where
         V: Eval<'a>,
+        O: MachineObserver<'a, V>,
     {
         match runtime.fun {
             DefaultFunction::AddInteger => {
@@ -846,6 +969,8 @@ impl<'a> Machine<'a> {
 
                 self.spend_budget(budget)?;
 
+                self.observer.trace(arg1);
+
                 self.logs.push(arg1.to_string());
 
                 Ok(arg2)
@@ -1,5 +1,3 @@
-use bumpalo::Bump;
-
 use crate::{binder::Eval, term::Term};
 
 use super::{context::Context, env::Env, value::Value};
@@ -13,28 +11,71 @@ where
     Done(&'a Term<'a, V>),
 }
 
+impl<'a, V> Clone for MachineState<'a, V>
+where
+    V: Eval<'a>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, V> Copy for MachineState<'a, V> where V: Eval<'a> {}
+
 impl<'a, V> MachineState<'a, V>
 where
     V: Eval<'a>,
 {
     pub fn compute(
-        arena: &'a Bump,
         context: &'a Context<'a, V>,
         env: &'a Env<'a, V>,
         term: &'a Term<'a, V>,
-    ) -> &'a mut MachineState<'a, V> {
-        arena.alloc(MachineState::Compute(context, env, term))
+    ) -> Self {
+        MachineState::Compute(context, env, term)
     }
 
-    pub fn return_(
-        arena: &'a Bump,
-        context: &'a Context<'a, V>,
-        value: &'a Value<'a, V>,
-    ) -> &'a mut MachineState<'a, V> {
-        arena.alloc(MachineState::Return(context, value))
+    pub fn return_(context: &'a Context<'a, V>, value: &'a Value<'a, V>) -> Self {
+        MachineState::Return(context, value)
+    }
+
+    pub fn done(term: &'a Term<'a, V>) -> Self {
+        MachineState::Done(term)
+    }
+
+    /// The term being computed, or the result once done.
+    pub fn term(&self) -> Option<&'a Term<'a, V>> {
+        match self {
+            MachineState::Compute(_, _, term) | MachineState::Done(term) => Some(term),
+            MachineState::Return(_, _) => None,
+        }
+    }
+
+    /// The value being returned to the context.
+    pub fn value(&self) -> Option<&'a Value<'a, V>> {
+        match self {
+            MachineState::Return(_, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The environment the term is computed in.
+    pub fn env(&self) -> Option<&'a Env<'a, V>> {
+        match self {
+            MachineState::Compute(_, env, _) => Some(env),
+            _ => None,
+        }
+    }
+
+    pub fn context(&self) -> Option<&'a Context<'a, V>> {
+        match self {
+            MachineState::Compute(context, _, _) | MachineState::Return(context, _) => {
+                Some(context)
+            }
+            MachineState::Done(_) => None,
+        }
     }
 
-    pub fn done(arena: &'a Bump, term: &'a Term<'a, V>) -> &'a mut MachineState<'a, V> {
-        arena.alloc(MachineState::Done(term))
+    pub fn is_done(&self) -> bool {
+        matches!(self, MachineState::Done(_))
     }
 }
@@ -0,0 +1,166 @@
+use crate::{binder::Eval, builtin::DefaultFunction};
+
+use super::{
+    context::Context,
+    cost_model::{MachineCosts, StepKind},
+    observer::MachineObserver,
+    runtime::Args,
+    ExBudget,
+};
+
+/// How often a step kind or builtin came up and what it spent in total.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tallied {
+    pub count: u64,
+    pub budget: ExBudget,
+}
+
+/// The consumed budget broken down by step kind and builtin, like the
+/// reference implementation's tallying budget mode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetTally {
+    pub startup: ExBudget,
+    /// Steps that were taken at least once, in `StepKind` order.
+    pub steps: Vec<(StepKind, Tallied)>,
+    /// Builtins that were called, most cpu first.
+    pub builtins: Vec<(DefaultFunction, Tallied)>,
+}
+
+impl BudgetTally {
+    pub fn total(&self) -> ExBudget {
+        self.steps
+            .iter()
+            .map(|(_, tallied)| tallied)
+            .chain(self.builtins.iter().map(|(_, tallied)| tallied))
+            .fold(self.startup, |total, tallied| total + tallied.budget)
+    }
+}
+
+/// Observer behind [`BudgetTally`], builtins are kept by their flat tag.
+pub(crate) struct Tallying {
+    machine_costs: MachineCosts,
+    steps: [u64; 9],
+    builtins: Vec<Option<(DefaultFunction, Tallied)>>,
+}
+
+impl Tallying {
+    pub(crate) fn new(machine_costs: MachineCosts) -> Self {
+        Tallying {
+            machine_costs,
+            steps: [0; 9],
+            builtins: Vec::new(),
+        }
+    }
+
+    pub(crate) fn finish(self) -> BudgetTally {
+        let steps = StepKind::ALL
+            .into_iter()
+            .filter(|kind| self.steps[*kind as usize] > 0)
+            .map(|kind| {
+                let count = self.steps[kind as usize];
+
+                let mut budget = self.machine_costs.get(kind as usize);
+
+                budget.occurrences(count as i64);
+
+                (kind, Tallied { count, budget })
+            })
+            .collect();
+
+        let mut builtins: Vec<_> = self.builtins.into_iter().flatten().collect();
+
+        builtins.sort_by_key(|(_, tallied)| std::cmp::Reverse(tallied.budget.cpu));
+
+        BudgetTally {
+            startup: self.machine_costs.startup(),
+            steps,
+            builtins,
+        }
+    }
+}
+
+impl<'a, V> MachineObserver<'a, V> for Tallying
+where
+    V: Eval<'a>,
+{
+    fn step(&mut self, kind: StepKind, _context: &'a Context<'a, V>) {
+        self.steps[kind as usize] += 1;
+    }
+
+    fn builtin_call(&mut self, fun: DefaultFunction, _args: &Args<'a, V>, cost: ExBudget) {
+        let tag = fun as usize;
+
+        if self.builtins.len() <= tag {
+            self.builtins.resize(tag + 1, None);
+        }
+
+        let (_, tallied) = self.builtins[tag].get_or_insert((
+            fun,
+            Tallied {
+                count: 0,
+                budget: ExBudget::new(0, 0),
+            },
+        ));
+
+        tallied.count += 1;
+        tallied.budget = tallied.budget + cost;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bumpalo::Bump;
+
+    use crate::{
+        binder::DeBruijn,
+        builtin::DefaultFunction,
+        machine::StepKind,
+        program::{EvalOptions, Program},
+        syn,
+    };
+
+    #[test]
+    fn tally_adds_up_to_consumed_budget() {
+        let arena = Bump::new();
+
+        let program: &Program<DeBruijn> = syn::parse_program(
+            &arena,
+            "(program 1.1.0 [(builtin addInteger) [(builtin addInteger) (con integer 1) (con integer 2)] (con integer 3)])",
+        )
+        .into_result()
+        .unwrap();
+
+        let result = program.eval_with(
+            &arena,
+            EvalOptions {
+                tally: true,
+                ..Default::default()
+            },
+        );
+
+        let tally = result.info.tally.unwrap();
+
+        assert_eq!(tally.total(), result.info.consumed_budget);
+
+        assert_eq!(tally.builtins.len(), 1);
+        assert_eq!(tally.builtins[0].0, DefaultFunction::AddInteger);
+        assert_eq!(tally.builtins[0].1.count, 2);
+
+        let kinds: Vec<_> = tally
+            .steps
+            .iter()
+            .map(|(kind, tallied)| (*kind, tallied.count))
+            .collect();
+
+        assert_eq!(
+            kinds,
+            [
+                (StepKind::Constant, 3),
+                (StepKind::Apply, 4),
+                (StepKind::Builtin, 2)
+            ]
+        );
+
+        assert!(program.eval(&arena).info.tally.is_none());
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
     typ::Type,
 };
 
-use super::{env::Env, runtime::Runtime, MachineError};
+use super::{discharge, env::Env, runtime::Runtime, MachineError};
 
 #[derive(Debug)]
 pub enum Value<'a, V>
@@ -89,6 +89,11 @@ where
         Value::con(arena, con)
     }
 
+    /// The value as a term, with its environment substituted in.
+    pub fn to_term(&'a self, arena: &'a Bump) -> &'a Term<'a, V> {
+        discharge::value_as_term(arena, self)
+    }
+
     pub fn unwrap_integer(&'a self) -> Result<&'a Integer, MachineError<'a, V>> {
         let inner = self.unwrap_constant()?;
 
@@ -0,0 +1,537 @@
+use std::fmt;
+
+use pretty::RcDoc;
+
+use crate::{
+    binder::{DeBruijn, Eval, Name, NamedDeBruijn},
+    bls,
+    constant::Constant,
+    data::PlutusData,
+    program::{Program, Version},
+    term::Term,
+    typ::Type,
+};
+
+pub const DEFAULT_WIDTH: usize = 80;
+
+/// How a binder is rendered in the textual syntax.
+///
+/// `scope` holds the names already given to the enclosing lambdas, outermost
+/// first, so a variable can be printed as the name of the lambda it refers to.
+pub trait PrettyBinder {
+    fn parameter_name(&self, scope: &[String]) -> String;
+
+    fn var_name(&self, scope: &[String]) -> String;
+}
+
+impl PrettyBinder for DeBruijn {
+    // A lambda at depth `d` binds `i_d`, so a variable refers to the lambda
+    // `index` levels above it.
+    fn parameter_name(&self, scope: &[String]) -> String {
+        format!("i_{}", scope.len())
+    }
+
+    // Indices that point outside the term can't be named, the parser reads
+    // any unbound name back as index 0.
+    fn var_name(&self, scope: &[String]) -> String {
+        bound_name(self.index(), scope).unwrap_or_else(|| format!("free_{}", self.index()))
+    }
+}
+
+impl PrettyBinder for NamedDeBruijn<'_> {
+    // The text is only a hint, flat decoding names every binder `i`, so a
+    // name that would shadow an enclosing one gets its depth appended.
+    fn parameter_name(&self, scope: &[String]) -> String {
+        let mut name = self.text().to_string();
+
+        if scope.contains(&name) {
+            name = format!("{name}_{}", scope.len());
+        }
+
+        while scope.contains(&name) {
+            name.push('\'');
+        }
+
+        name
+    }
+
+    fn var_name(&self, scope: &[String]) -> String {
+        bound_name(self.index(), scope).unwrap_or_else(|| self.text().to_string())
+    }
+}
+
+impl PrettyBinder for Name<'_> {
+    fn parameter_name(&self, _scope: &[String]) -> String {
+        self.text().to_string()
+    }
+
+    fn var_name(&self, _scope: &[String]) -> String {
+        self.text().to_string()
+    }
+}
+
+/// The name given to the lambda a de Bruijn `index` refers to.
+fn bound_name(index: usize, scope: &[String]) -> Option<String> {
+    if index == 0 || index > scope.len() {
+        None
+    } else {
+        Some(scope[scope.len() - index].clone())
+    }
+}
+
+impl<V> Program<'_, V>
+where
+    V: PrettyBinder,
+{
+    pub fn to_pretty(&self) -> String {
+        self.to_pretty_width(DEFAULT_WIDTH)
+    }
+
+    pub fn to_pretty_width(&self, width: usize) -> String {
+        self.to_doc().pretty(width).to_string()
+    }
+
+    pub fn to_doc(&self) -> RcDoc<'static> {
+        RcDoc::text("(")
+            .append(RcDoc::text("program"))
+            .append(
+                RcDoc::line()
+                    .append(RcDoc::as_string(self.version))
+                    .append(RcDoc::line())
+                    .append(self.term.to_doc())
+                    .nest(2),
+            )
+            .append(RcDoc::line_())
+            .append(RcDoc::text(")"))
+            .group()
+    }
+}
+
+impl<V> Term<'_, V>
+where
+    V: PrettyBinder,
+{
+    pub fn to_pretty(&self) -> String {
+        self.to_pretty_width(DEFAULT_WIDTH)
+    }
+
+    pub fn to_pretty_width(&self, width: usize) -> String {
+        self.to_doc().pretty(width).to_string()
+    }
+
+    pub fn to_doc(&self) -> RcDoc<'static> {
+        self.to_doc_in(&mut Vec::new())
+    }
+
+    fn to_doc_in(&self, scope: &mut Vec<String>) -> RcDoc<'static> {
+        match self {
+            Term::Var(name) => RcDoc::text(name.var_name(scope)),
+            Term::Lambda { parameter, body } => {
+                let name = parameter.parameter_name(scope);
+
+                scope.push(name.clone());
+
+                let body = body.to_doc_in(scope);
+
+                scope.pop();
+
+                keyword_doc("lam", Some(name), vec![body])
+            }
+            Term::Apply { .. } => {
+                let mut args = Vec::new();
+                let mut function = self;
+
+                while let Term::Apply {
+                    function: f,
+                    argument,
+                } = function
+                {
+                    args.push(argument.to_doc_in(scope));
+
+                    function = f;
+                }
+
+                args.push(function.to_doc_in(scope));
+
+                args.reverse();
+
+                RcDoc::text("[")
+                    .append(
+                        RcDoc::line_()
+                            .append(RcDoc::intersperse(args, RcDoc::line()))
+                            .nest(2),
+                    )
+                    .append(RcDoc::line_())
+                    .append(RcDoc::text("]"))
+                    .group()
+            }
+            Term::Delay(body) => keyword_doc("delay", None, vec![body.to_doc_in(scope)]),
+            Term::Force(body) => keyword_doc("force", None, vec![body.to_doc_in(scope)]),
+            Term::Case { constr, branches } => {
+                let mut children = vec![constr.to_doc_in(scope)];
+
+                children.extend(branches.iter().map(|branch| branch.to_doc_in(scope)));
+
+                keyword_doc("case", None, children)
+            }
+            Term::Constr { tag, fields } => keyword_doc(
+                "constr",
+                Some(tag.to_string()),
+                fields.iter().map(|field| field.to_doc_in(scope)).collect(),
+            ),
+            Term::Constant(constant) => RcDoc::text(format!("(con {constant})")),
+            Term::Builtin(fun) => RcDoc::text(format!("(builtin {fun})")),
+            Term::Error => RcDoc::text("(error)"),
+        }
+    }
+}
+
+// `(keyword head child...)`, breaking every child onto its own line when the
+// group doesn't fit.
+fn keyword_doc(
+    keyword: &'static str,
+    head: Option<String>,
+    children: Vec<RcDoc<'static>>,
+) -> RcDoc<'static> {
+    let mut doc = RcDoc::text("(").append(RcDoc::text(keyword));
+
+    if let Some(head) = head {
+        doc = doc.append(RcDoc::space()).append(RcDoc::text(head));
+    }
+
+    let children = children.into_iter().fold(RcDoc::nil(), |acc, child| {
+        acc.append(RcDoc::line()).append(child)
+    });
+
+    doc.append(children.nest(2))
+        .append(RcDoc::line_())
+        .append(RcDoc::text(")"))
+        .group()
+}
+
+/// Prints the textual syntax, which [`crate::syn::parse_program`] reads back
+/// to the same program unless it holds a free variable, printed as `free_N`
+/// and read back as index 0, or a `bls12_381_mlresult` constant, which has no
+/// textual syntax and prints as `<bls12_381_mlresult>`.
+impl<V> fmt::Display for Program<'_, V>
+where
+    V: PrettyBinder,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_doc()
+            .render_fmt(f.width().unwrap_or(DEFAULT_WIDTH), f)
+    }
+}
+
+/// Prints the textual syntax, see the `Program` impl for what doesn't parse
+/// back.
+impl<V> fmt::Display for Term<'_, V>
+where
+    V: PrettyBinder,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_doc()
+            .render_fmt(f.width().unwrap_or(DEFAULT_WIDTH), f)
+    }
+}
+
+impl fmt::Display for Version<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major(), self.minor(), self.patch())
+    }
+}
+
+/// Renders as `type value`, the part of a constant term after `con`.
+impl fmt::Display for Constant<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_constant_type(f, self)?;
+
+        f.write_str(" ")?;
+
+        match self {
+            // top level data is parenthesised like the reference printer does
+            Constant::Data(data) => write!(f, "({data})"),
+            _ => write_constant_value(f, self),
+        }
+    }
+}
+
+// The same as `type_of` but without an arena to build the type in.
+fn write_constant_type(f: &mut fmt::Formatter<'_>, constant: &Constant<'_>) -> fmt::Result {
+    match constant {
+        Constant::Integer(_) => write!(f, "{}", Type::Integer),
+        Constant::ByteString(_) => write!(f, "{}", Type::ByteString),
+        Constant::String(_) => write!(f, "{}", Type::String),
+        Constant::Boolean(_) => write!(f, "{}", Type::Bool),
+        Constant::Data(_) => write!(f, "{}", Type::Data),
+        Constant::ProtoList(inner, _) => write!(f, "(list {inner})"),
+        Constant::ProtoArray(inner, _) => write!(f, "(array {inner})"),
+        Constant::ProtoPair(fst, snd, _, _) => write!(f, "(pair {fst} {snd})"),
+        Constant::Unit => write!(f, "{}", Type::Unit),
+        Constant::Bls12_381G1Element(_) => write!(f, "{}", Type::Bls12_381G1Element),
+        Constant::Bls12_381G2Element(_) => write!(f, "{}", Type::Bls12_381G2Element),
+        Constant::Bls12_381MlResult(_) => write!(f, "{}", Type::Bls12_381MlResult),
+    }
+}
+
+fn write_constant_value(f: &mut fmt::Formatter<'_>, constant: &Constant<'_>) -> fmt::Result {
+    match constant {
+        Constant::Integer(i) => write!(f, "{i}"),
+        Constant::ByteString(bytes) => write!(f, "#{}", hex::encode(bytes)),
+        Constant::String(s) => write_string(f, s),
+        Constant::Boolean(b) => f.write_str(if *b { "True" } else { "False" }),
+        Constant::Data(data) => write!(f, "{data}"),
+        Constant::ProtoList(_, items) | Constant::ProtoArray(_, items) => {
+            f.write_str("[")?;
+
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    f.write_str(", ")?;
+                }
+
+                write_constant_value(f, item)?;
+            }
+
+            f.write_str("]")
+        }
+        Constant::ProtoPair(_, _, fst, snd) => {
+            f.write_str("(")?;
+            write_constant_value(f, fst)?;
+            f.write_str(", ")?;
+            write_constant_value(f, snd)?;
+            f.write_str(")")
+        }
+        Constant::Unit => f.write_str("()"),
+        Constant::Bls12_381G1Element(g1) => write!(f, "0x{}", hex::encode(bls::g1_compressed(g1))),
+        Constant::Bls12_381G2Element(g2) => write!(f, "0x{}", hex::encode(bls::g2_compressed(g2))),
+        // there is no textual syntax for miller loop results
+        Constant::Bls12_381MlResult(_) => f.write_str("<bls12_381_mlresult>"),
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_str("\"")?;
+
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c => write!(f, "{c}")?,
+        }
+    }
+
+    f.write_str("\"")
+}
+
+impl fmt::Display for PlutusData<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlutusData::Constr { tag, fields } => {
+                write!(f, "Constr {tag} [")?;
+
+                for (index, field) in fields.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    write!(f, "{field}")?;
+                }
+
+                f.write_str("]")
+            }
+            PlutusData::Map(items) => {
+                f.write_str("Map [")?;
+
+                for (index, (key, value)) in items.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    write!(f, "({key}, {value})")?;
+                }
+
+                f.write_str("]")
+            }
+            PlutusData::Integer(i) => write!(f, "I {i}"),
+            PlutusData::ByteString(bytes) => write!(f, "B #{}", hex::encode(bytes)),
+            PlutusData::List(items) => {
+                f.write_str("List [")?;
+
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    write!(f, "{item}")?;
+                }
+
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+impl fmt::Display for Type<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Bool => f.write_str("bool"),
+            Type::Integer => f.write_str("integer"),
+            Type::String => f.write_str("string"),
+            Type::ByteString => f.write_str("bytestring"),
+            Type::Unit => f.write_str("unit"),
+            Type::List(inner) => write!(f, "(list {inner})"),
+            Type::Array(inner) => write!(f, "(array {inner})"),
+            Type::Pair(fst, snd) => write!(f, "(pair {fst} {snd})"),
+            Type::Data => f.write_str("data"),
+            Type::Bls12_381G1Element => f.write_str("bls12_381_G1_element"),
+            Type::Bls12_381G2Element => f.write_str("bls12_381_G2_element"),
+            Type::Bls12_381MlResult => f.write_str("bls12_381_mlresult"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bumpalo::Bump;
+    use pretty_assertions::assert_eq;
+
+    use crate::{binder::DeBruijn, constant::Constant, program::Program, syn, term::Term};
+
+    #[test]
+    fn program_fits_on_one_line() {
+        let arena = Bump::new();
+
+        let program = syn::parse_program(
+            &arena,
+            "(program 1.1.0 [(lam x [(builtin addInteger) x (con integer 1)]) (con integer 2)])",
+        )
+        .into_result()
+        .unwrap();
+
+        assert_eq!(
+            program.to_pretty_width(100),
+            "(program 1.1.0 [(lam i_0 [(builtin addInteger) i_0 (con integer 1)]) (con integer 2)])"
+        );
+    }
+
+    #[test]
+    fn program_breaks_when_too_wide() {
+        let arena = Bump::new();
+
+        let program = syn::parse_program(
+            &arena,
+            "(program 1.0.0 (lam x (lam y [(builtin appendString) x y])))",
+        )
+        .into_result()
+        .unwrap();
+
+        assert_eq!(
+            program.to_pretty_width(30),
+            r#"(program
+  1.0.0
+  (lam i_0
+    (lam i_1
+      [
+        (builtin appendString)
+        i_0
+        i_1
+      ]
+    )
+  )
+)"#
+        );
+    }
+
+    #[test]
+    fn constants() {
+        let arena = Bump::new();
+
+        let term = syn::parse_term(
+            &arena,
+            r#"(con (list (pair integer string)) [(1, "a\"b\n"), (-2, "")])"#,
+        )
+        .into_result()
+        .unwrap();
+
+        assert_eq!(
+            term.to_pretty(),
+            r#"(con (list (pair integer string)) [(1, "a\"b\n"), (-2, "")])"#
+        );
+
+        let term: &Term<DeBruijn> = Term::data(
+            &arena,
+            syn::parse_data(&arena, "Constr 1 [Map [(I 1, B #ff)], List [I -3]]")
+                .into_result()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            term.to_pretty(),
+            "(con data (Constr 1 [Map [(I 1, B #ff)], List [I -3]]))"
+        );
+    }
+
+    #[test]
+    fn reparses_to_same_term() {
+        let arena = Bump::new();
+
+        let source = "(program 1.1.0 (case (constr 1 (con bool True) (delay (error))) (lam a a) (lam b (lam c (force c)))))";
+
+        let program: &Program<DeBruijn> = syn::parse_program(&arena, source).into_result().unwrap();
+
+        let printed = program.to_pretty_width(10);
+
+        let reparsed = syn::parse_program(&arena, &printed).into_result().unwrap();
+
+        assert_eq!(program.term, reparsed.term);
+    }
+
+    #[test]
+    fn named_debruijn_names_shadowed_binders_apart() {
+        let arena = Bump::new();
+
+        let program: &Program<DeBruijn> =
+            syn::parse_program(&arena, "(program 1.0.0 (lam a (lam b [a b])))")
+                .into_result()
+                .unwrap();
+
+        let named = program.to_named_debruijn(&arena);
+
+        let printed = named.to_pretty();
+
+        assert_eq!(printed, "(program 1.0.0 (lam i (lam i_1 [i i_1])))");
+
+        let reparsed = syn::parse_program(&arena, &printed).into_result().unwrap();
+
+        assert_eq!(program.term, reparsed.term);
+    }
+
+    #[test]
+    fn free_variables_and_ml_results_do_not_parse_back() {
+        let arena = Bump::new();
+
+        let term: &Term<DeBruijn> =
+            Term::var(&arena, DeBruijn::new(&arena, 2)).lambda(&arena, DeBruijn::zero(&arena));
+
+        let printed = term.to_pretty();
+
+        assert_eq!(printed, "(lam i_0 free_2)");
+        assert!(syn::parse_term_strict(&arena, &printed).has_errors());
+
+        let reparsed = syn::parse_term(&arena, &printed).into_result().unwrap();
+
+        assert_ne!(term, reparsed);
+
+        let ml_result = arena.alloc(blst::blst_fp12::default());
+
+        let term: &Term<DeBruijn> = Term::constant(&arena, Constant::ml_result(&arena, ml_result));
+
+        let printed = term.to_pretty();
+
+        assert_eq!(printed, "(con bls12_381_mlresult <bls12_381_mlresult>)");
+        assert!(syn::parse_term(&arena, &printed).has_errors());
+    }
+}
@@ -1,12 +1,19 @@
 use bumpalo::Bump;
 
 use crate::{
-    binder::Eval,
-    machine::{BuiltinSemantics, CostModel, EvalResult, ExBudget, Machine, PlutusVersion},
+    binder::{Binder, Eval},
+    data::PlutusData,
+    flat::{self, FlatEncodeError},
+    machine::{
+        CostModel, EvalResult, ExBudget, Machine, MachineObserver, PlutusVersion, ProtocolVersion,
+        Tallying,
+    },
     term::Term,
 };
 
-#[derive(Debug)]
+pub const SCRIPT_HASH_SIZE: usize = 28;
+
+#[derive(Debug, PartialEq)]
 pub struct Program<'a, V> {
     pub version: &'a Version<'a>,
     pub term: &'a Term<'a, V>,
@@ -24,6 +31,17 @@ impl<'a, V> Program<'a, V> {
 
         Self::new(arena, self.version, term)
     }
+
+    pub fn apply_data(&'a self, arena: &'a Bump, data: &'a PlutusData<'a>) -> &'a Self {
+        self.apply(arena, Term::data(arena, data))
+    }
+
+    /// Applies each parameter in order, as a `data` constant.
+    pub fn apply_params(&'a self, arena: &'a Bump, params: &[&'a PlutusData<'a>]) -> &'a Self {
+        params
+            .iter()
+            .fold(self, |program, param| program.apply_data(arena, param))
+    }
 }
 
 impl<'a, V> Program<'a, V>
@@ -40,23 +58,117 @@ where
         arena: &'a Bump,
         plutus_version: PlutusVersion,
     ) -> EvalResult<'a, V> {
-        let mut machine = Machine::new(
-            arena,
-            ExBudget::default(),
-            CostModel::default(),
-            BuiltinSemantics::from(&plutus_version),
-        );
+        self.eval_with(arena, EvalOptions::for_version(plutus_version))
+    }
+
+    pub fn eval_with(&'a self, arena: &'a Bump, options: EvalOptions) -> EvalResult<'a, V> {
+        // the machine borrows the cost model for as long as the arena lives
+        self.eval_borrowing(arena, arena.alloc(options))
+    }
+
+    /// Like [`Program::eval_with`], borrowing the options so they can be
+    /// reused across runs.
+    pub fn eval_borrowing(
+        &'a self,
+        arena: &'a Bump,
+        options: &'a EvalOptions,
+    ) -> EvalResult<'a, V> {
+        if options.tally {
+            let tallying = Tallying::new(options.cost_model.machine_costs.clone());
+
+            let (mut result, tallying) = self.eval_with_observer(arena, options, tallying);
+
+            result.info.tally = Some(tallying.finish());
+
+            result
+        } else {
+            self.eval_with_observer(arena, options, ()).0
+        }
+    }
+
+    /// Like [`Program::eval_with`], also handing back the observer.
+    pub fn eval_with_observer<O>(
+        &'a self,
+        arena: &'a Bump,
+        options: &'a EvalOptions,
+        observer: O,
+    ) -> (EvalResult<'a, V>, O)
+    where
+        O: MachineObserver<'a, V>,
+    {
+        let mut machine = Machine::with_observer(arena, options, self.version, observer);
 
         let term = machine.run(self.term);
-        let mut info = machine.info();
+        let (mut info, observer) = machine.finish();
+
+        info.consumed_budget = options.budget - info.consumed_budget;
+
+        (EvalResult { term, info }, observer)
+    }
+}
+
+/// What a program is evaluated with, the consumed budget is reported relative
+/// to `budget`.
+#[derive(Debug, Clone)]
+pub struct EvalOptions {
+    pub budget: ExBudget,
+    pub cost_model: CostModel,
+    pub plutus_version: PlutusVersion,
+    /// Builtins the ledger hasn't enabled for `plutus_version` at this
+    /// protocol version fail evaluation.
+    pub protocol_version: ProtocolVersion,
+    /// Break the consumed budget down per step kind and builtin, see
+    /// [`MachineInfo::tally`](crate::machine::MachineInfo::tally).
+    pub tally: bool,
+}
+
+impl Default for EvalOptions {
+    fn default() -> Self {
+        Self::for_version(PlutusVersion::V3)
+    }
+}
+
+impl EvalOptions {
+    /// The default budget along with the ledger's cost model for `plutus_version`.
+    pub fn for_version(plutus_version: PlutusVersion) -> Self {
+        Self {
+            budget: ExBudget::default(),
+            cost_model: CostModel::for_version(plutus_version),
+            plutus_version,
+            protocol_version: ProtocolVersion::default(),
+            tally: false,
+        }
+    }
+}
+
+impl<'a, V> Program<'a, V>
+where
+    V: Binder<'a>,
+{
+    /// The ledger script hash, blake2b-224 over the language tag followed by
+    /// the CBOR wrapped flat bytes.
+    pub fn hash(
+        &'a self,
+        plutus_version: PlutusVersion,
+    ) -> Result<[u8; SCRIPT_HASH_SIZE], FlatEncodeError> {
+        use cryptoxide::{blake2b::Blake2b, digest::Digest};
+
+        let cbor = flat::encode_cbor(self)?;
+
+        let mut context = Blake2b::new(SCRIPT_HASH_SIZE);
+
+        context.input(&[plutus_version.language_tag()]);
+        context.input(&cbor);
 
-        info.consumed_budget = ExBudget::default() - info.consumed_budget;
+        let mut digest = [0; SCRIPT_HASH_SIZE];
 
-        EvalResult { term, info }
+        context.result(&mut digest);
+
+        Ok(digest)
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Version<'a>(&'a (usize, usize, usize));
 
 impl<'a> Version<'a> {
@@ -106,3 +218,129 @@ impl<'a> Version<'a> {
         self.0 .2
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bumpalo::Bump;
+
+    use crate::{
+        binder::DeBruijn,
+        data::PlutusData,
+        flat,
+        machine::{ExBudget, MachineError, PlutusVersion},
+        syn,
+        term::Term,
+    };
+
+    use super::{EvalOptions, Program, Version};
+
+    #[test]
+    fn hash_always_succeeds_v1() {
+        let arena = Bump::new();
+
+        let program: &Program<DeBruijn> =
+            flat::decode_hex(&arena, "4e4d01000033222220051200120011").unwrap();
+
+        let hash = program.hash(PlutusVersion::V1).unwrap();
+
+        assert_eq!(
+            hex::encode(hash),
+            "67f33146617a5e61936081db3b2117cbf59bd2123748f58ac9678656"
+        );
+    }
+
+    #[test]
+    fn apply_params_in_order() {
+        let arena = Bump::new();
+
+        let program = syn::parse_program(
+            &arena,
+            "(program 1.1.0 (lam a (lam b [(builtin subtractInteger) [(builtin unIData) a] [(builtin unIData) b]])))",
+        )
+        .into_result()
+        .unwrap();
+
+        let params = [
+            PlutusData::integer_from(&arena, 10),
+            PlutusData::from_cbor(&arena, &[0x03]).unwrap(),
+        ];
+
+        let applied = program.apply_params(&arena, &params);
+
+        let result = applied.eval(&arena);
+
+        assert_eq!(result.term.unwrap(), Term::integer_from(&arena, 7));
+    }
+
+    #[test]
+    fn eval_with_budget() {
+        let arena = Bump::new();
+
+        let program: &Program<DeBruijn> = syn::parse_program(
+            &arena,
+            "(program 1.1.0 [(builtin addInteger) (con integer 1) (con integer 2)])",
+        )
+        .into_result()
+        .unwrap();
+
+        let consumed = program.eval(&arena).info.consumed_budget;
+
+        let exact = program.eval_with(
+            &arena,
+            EvalOptions {
+                budget: consumed,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(exact.term.unwrap(), Term::integer_from(&arena, 3));
+        assert_eq!(exact.info.consumed_budget, consumed);
+
+        let short = program.eval_with(
+            &arena,
+            EvalOptions {
+                budget: ExBudget::new(consumed.mem, consumed.cpu - 1),
+                ..Default::default()
+            },
+        );
+
+        assert!(matches!(short.term, Err(MachineError::OutOfExError(_))));
+    }
+
+    #[test]
+    fn eval_version_costs_by_version() {
+        let arena = Bump::new();
+
+        let program: &Program<DeBruijn> = syn::parse_program(
+            &arena,
+            "(program 1.0.0 [(builtin divideInteger) (con integer 10) (con integer 3)])",
+        )
+        .into_result()
+        .unwrap();
+
+        let v2 = program.eval_version(&arena, PlutusVersion::V2);
+        let v3 = program.eval_version(&arena, PlutusVersion::V3);
+
+        assert_eq!(v2.term.unwrap(), Term::integer_from(&arena, 3));
+        assert_eq!(v3.term.unwrap(), Term::integer_from(&arena, 3));
+
+        // both arguments are one word, so V2's division costs 228465 + 122
+        assert_eq!(
+            v2.info.consumed_budget.cpu - v3.info.consumed_budget.cpu,
+            (228465 + 122) - (123203 + 7305 + -900 + 1716 + 549 + 57)
+        );
+    }
+
+    #[test]
+    fn eval_rejects_constr_before_1_1_0() {
+        let arena = Bump::new();
+
+        let term = Term::constr(&arena, 0, arena.alloc([Term::integer_from(&arena, 1)]));
+
+        let program: &Program<DeBruijn> = Program::new(&arena, Version::plutus_v1(&arena), term);
+
+        let result = program.eval_version(&arena, PlutusVersion::V1);
+
+        assert!(matches!(result.term, Err(MachineError::UnsupportedTerm(_))));
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt;
+
+use crate::{binder::Eval, program::Program, term::Term};
+
+/// A step from a term into one of its children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathSegment {
+    /// The body of a `lam`, `delay` or `force`.
+    Body,
+    Function,
+    Argument,
+    Scrutinee,
+    Branch(usize),
+    Field(usize),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathSegment::Body => f.write_str("body"),
+            PathSegment::Function => f.write_str("function"),
+            PathSegment::Argument => f.write_str("argument"),
+            PathSegment::Scrutinee => f.write_str("scrutinee"),
+            PathSegment::Branch(index) => write!(f, "branch {index}"),
+            PathSegment::Field(index) => write!(f, "field {index}"),
+        }
+    }
+}
+
+/// A variable whose index doesn't point at any enclosing lambda.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error(
+    "{} index {index} at depth {depth}, path: {}",
+    if *.index == 0 { "zero" } else { "free" },
+    display_path(.path)
+)]
+pub struct ScopeError {
+    pub index: usize,
+    /// Number of lambdas enclosing the variable.
+    pub depth: usize,
+    /// Segments leading from the program's root term to the variable.
+    pub path: Vec<PathSegment>,
+}
+
+fn display_path(path: &[PathSegment]) -> String {
+    if path.is_empty() {
+        return "root".to_string();
+    }
+
+    path.iter()
+        .map(|segment| segment.to_string())
+        .collect::<Vec<_>>()
+        .join(" > ")
+}
+
+impl<'a, V> Program<'a, V>
+where
+    V: Eval<'a>,
+{
+    /// Walks the term once and reports every variable that is free or has
+    /// index zero, the first one found being the first in the list.
+    pub fn check_scope(&self) -> Result<(), Vec<ScopeError>> {
+        self.term.check_scope()
+    }
+}
+
+impl<'a, V> Term<'a, V>
+where
+    V: Eval<'a>,
+{
+    pub fn check_scope(&self) -> Result<(), Vec<ScopeError>> {
+        let mut errors = Vec::new();
+        let mut path = Vec::new();
+
+        check(self, 0, &mut path, &mut errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn check<'a, V>(
+    term: &Term<'a, V>,
+    depth: usize,
+    path: &mut Vec<PathSegment>,
+    errors: &mut Vec<ScopeError>,
+) where
+    V: Eval<'a>,
+{
+    let mut visit = |segment, child, depth, path: &mut Vec<PathSegment>| {
+        path.push(segment);
+
+        check(child, depth, path, errors);
+
+        path.pop();
+    };
+
+    match term {
+        Term::Var(name) => {
+            let index = name.index();
+
+            if index == 0 || index > depth {
+                errors.push(ScopeError {
+                    index,
+                    depth,
+                    path: path.clone(),
+                });
+            }
+        }
+        Term::Lambda { body, .. } => visit(PathSegment::Body, body, depth + 1, path),
+        Term::Apply { function, argument } => {
+            visit(PathSegment::Function, function, depth, path);
+            visit(PathSegment::Argument, argument, depth, path);
+        }
+        Term::Delay(body) | Term::Force(body) => visit(PathSegment::Body, body, depth, path),
+        Term::Case { constr, branches } => {
+            visit(PathSegment::Scrutinee, constr, depth, path);
+
+            for (index, branch) in branches.iter().enumerate() {
+                visit(PathSegment::Branch(index), branch, depth, path);
+            }
+        }
+        Term::Constr { fields, .. } => {
+            for (index, field) in fields.iter().enumerate() {
+                visit(PathSegment::Field(index), field, depth, path);
+            }
+        }
+        Term::Constant(_) | Term::Builtin(_) | Term::Error => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bumpalo::Bump;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        binder::DeBruijn,
+        flat,
+        program::{Program, Version},
+        syn,
+        term::Term,
+    };
+
+    use super::{PathSegment, ScopeError};
+
+    #[test]
+    fn closed_program_is_in_scope() {
+        let arena = Bump::new();
+
+        let program = syn::parse_program(&arena, "(program 1.1.0 (lam x (lam y [x (delay y)])))")
+            .into_result()
+            .unwrap();
+
+        assert_eq!(program.check_scope(), Ok(()));
+    }
+
+    #[test]
+    fn reports_every_out_of_scope_index() {
+        let arena = Bump::new();
+
+        let term = Term::var(&arena, DeBruijn::new(&arena, 2))
+            .apply(
+                &arena,
+                Term::var(&arena, DeBruijn::zero(&arena)).delay(&arena),
+            )
+            .lambda(&arena, DeBruijn::zero(&arena));
+
+        let version = Version::plutus_v3(&arena);
+
+        let program = Program::new(&arena, version, term);
+
+        // round trip through flat, which happily accepts these indices
+        let bytes = flat::encode(program).unwrap();
+        let program: &Program<DeBruijn> = flat::decode(&arena, &bytes).unwrap();
+
+        let errors = program.check_scope().unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                ScopeError {
+                    index: 2,
+                    depth: 1,
+                    path: vec![PathSegment::Body, PathSegment::Function],
+                },
+                ScopeError {
+                    index: 0,
+                    depth: 1,
+                    path: vec![PathSegment::Body, PathSegment::Argument, PathSegment::Body],
+                },
+            ]
+        );
+
+        assert_eq!(
+            errors[0].to_string(),
+            "free index 2 at depth 1, path: body > function"
+        );
+    }
+}
@@ -10,7 +10,13 @@ mod types;
 mod utils;
 mod version;
 
-use crate::{binder::DeBruijn, constant::Constant, data::PlutusData, program::Program, term::Term};
+use crate::{
+    binder::{DeBruijn, Name},
+    constant::Constant,
+    data::PlutusData,
+    program::Program,
+    term::Term,
+};
 
 pub fn parse_program<'a>(
     arena: &'a Bump,
@@ -21,6 +27,28 @@ pub fn parse_program<'a>(
     program::parser().parse_with_state(input, &mut initial_state)
 }
 
+/// Like [`parse_program`] but unbound variables are a parse error rather than
+/// an index 0 placeholder that fails at evaluation time.
+pub fn parse_program_strict<'a>(
+    arena: &'a Bump,
+    input: &'a str,
+) -> ParseResult<&'a Program<'a, DeBruijn>, Rich<'a, char>> {
+    let mut initial_state = types::State::strict(arena);
+
+    program::parser().parse_with_state(input, &mut initial_state)
+}
+
+/// Like [`parse_program`] but keeps the textual names, every binder gets a
+/// unique id and variables share the id of the lambda that binds them.
+pub fn parse_named_program<'a>(
+    arena: &'a Bump,
+    input: &'a str,
+) -> ParseResult<&'a Program<'a, Name<'a>>, Rich<'a, char>> {
+    let mut initial_state = types::State::new(arena);
+
+    program::parser().parse_with_state(input, &mut initial_state)
+}
+
 pub fn parse_term<'a>(
     arena: &'a Bump,
     input: &'a str,
@@ -30,6 +58,24 @@ pub fn parse_term<'a>(
     term::parser().parse_with_state(input, &mut initial_state)
 }
 
+pub fn parse_term_strict<'a>(
+    arena: &'a Bump,
+    input: &'a str,
+) -> ParseResult<&'a Term<'a, DeBruijn>, Rich<'a, char>> {
+    let mut initial_state = types::State::strict(arena);
+
+    term::parser().parse_with_state(input, &mut initial_state)
+}
+
+pub fn parse_named_term<'a>(
+    arena: &'a Bump,
+    input: &'a str,
+) -> ParseResult<&'a Term<'a, Name<'a>>, Rich<'a, char>> {
+    let mut initial_state = types::State::new(arena);
+
+    term::parser().parse_with_state(input, &mut initial_state)
+}
+
 pub fn parse_constant<'a>(
     arena: &'a Bump,
     input: &'a str,
@@ -47,3 +93,50 @@ pub fn parse_data<'a>(
 
     data::parser().parse_with_state(input, &mut initial_state)
 }
+
+#[cfg(test)]
+mod tests {
+    use bumpalo::Bump;
+
+    use crate::{binder::DeBruijn, term::Term};
+
+    #[test]
+    fn strict_mode_reports_unbound_variables() {
+        let arena = Bump::new();
+
+        let input = "(program 1.1.0 (lam x [x y]))";
+
+        let errs = super::parse_program_strict(&arena, input)
+            .into_result()
+            .unwrap_err();
+
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].to_string(), "unbound variable y");
+        assert_eq!(&input[errs[0].span().into_range()], "y");
+    }
+
+    #[test]
+    fn strict_mode_accepts_closed_terms() {
+        let arena = Bump::new();
+
+        let term = super::parse_term_strict(&arena, "(lam x (lam y [x y]))")
+            .into_result()
+            .unwrap();
+
+        assert_eq!(
+            term,
+            super::parse_term(&arena, "(lam x (lam y [x y]))")
+                .into_result()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn lenient_mode_keeps_placeholder() {
+        let arena = Bump::new();
+
+        let term = super::parse_term(&arena, "y").into_result().unwrap();
+
+        assert_eq!(term, Term::var(&arena, DeBruijn::zero(&arena)));
+    }
+}
@@ -1,10 +1,18 @@
 use chumsky::{prelude::*, Parser};
 
-use crate::{binder::DeBruijn, program::Program};
+use crate::program::Program;
 
-use super::{term, types::Extra, utils::comments, version};
+use super::{
+    term,
+    types::{Extra, ParseBinder},
+    utils::comments,
+    version,
+};
 
-pub fn parser<'a>() -> impl Parser<'a, &'a str, &'a Program<'a, DeBruijn>, Extra<'a>> {
+pub fn parser<'a, V>() -> impl Parser<'a, &'a str, &'a Program<'a, V>, Extra<'a>>
+where
+    V: ParseBinder<'a>,
+{
     text::keyword("program")
         .padded()
         .ignore_then(version::parser().padded())
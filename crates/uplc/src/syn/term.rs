@@ -1,44 +1,41 @@
 use bumpalo::{collections::Vec as BumpVec, Bump};
 use chumsky::{prelude::*, Parser};
 
-use crate::{binder::DeBruijn, term::Term};
+use crate::term::Term;
 
 use super::{
     constant,
-    types::{Extra, MapExtra},
+    types::{Extra, MapExtra, ParseBinder},
     utils::{comments, name},
 };
 
-pub fn parser<'a>() -> impl Parser<'a, &'a str, &'a Term<'a, DeBruijn>, Extra<'a>> {
+pub fn parser<'a, V>() -> impl Parser<'a, &'a str, &'a Term<'a, V>, Extra<'a>>
+where
+    V: ParseBinder<'a>,
+{
     recursive(|term| {
         choice((
             // Var
-            name().padded().map_with(|v, e: &mut MapExtra<'a, '_>| {
-                let state = e.state();
-
-                let position = state.env.iter().rposition(|&x| x == v);
-
-                if position.is_none() {
-                    let placeholder = Term::var(state.arena, DeBruijn::zero(state.arena));
+            name()
+                .validate(|v, e: &mut MapExtra<'a, '_>, emitter| {
+                    let span = e.span();
+                    let state = e.state();
 
-                    // this will fail at eval time
-                    // the conformance tests don't expect this
-                    // to fail at parse time
-                    placeholder
-                } else {
-                    let debruijn_index = state.env.len() - position.unwrap_or_default();
+                    if state.strict && state.lookup(v).is_none() {
+                        emitter.emit(Rich::custom(span, format!("unbound variable {v}")));
+                    }
 
-                    let d = DeBruijn::new(state.arena, debruijn_index);
+                    let var = V::var(state, v);
 
-                    Term::var(state.arena, d)
-                }
-            }),
+                    Term::var(state.arena, var)
+                })
+                .padded(),
             // Delay
             text::keyword("delay")
                 .padded()
                 .ignore_then(term.clone().padded())
                 .delimited_by(just('('), just(')'))
-                .map_with(|term: &Term<'_, DeBruijn>, e: &mut MapExtra<'a, '_>| {
+                .map_with(|term: &Term<'_, V>, e: &mut MapExtra<'a, '_>| {
                     let state = e.state();
 
                     term.delay(state.arena)
@@ -60,20 +57,16 @@ pub fn parser<'a>() -> impl Parser<'a, &'a str, &'a Term<'a, DeBruijn>, Extra<'a
                 .map_with(|v, e: &mut MapExtra<'a, '_>| {
                     let state = e.state();
 
-                    state.env.push(v);
-
-                    0
+                    V::parameter(state, v)
                 })
                 .then(term.clone().padded())
                 .delimited_by(just('('), just(')'))
-                .map_with(|(v, term), e| {
+                .map_with(|(parameter, term), e| {
                     let state = e.state();
 
                     state.env.pop();
 
-                    let d = DeBruijn::new(state.arena, v);
-
-                    term.lambda(state.arena, d)
+                    term.lambda(state.arena, parameter)
                 }),
             // Apply
             term.clone()
@@ -125,7 +118,7 @@ pub fn parser<'a>() -> impl Parser<'a, &'a str, &'a Term<'a, DeBruijn>, Extra<'a
                     term.clone()
                         .padded()
                         .repeated()
-                        .collect::<Vec<&Term<'_, DeBruijn>>>(),
+                        .collect::<Vec<&Term<'_, V>>>(),
                 )
                 .delimited_by(just('('), just(')'))
                 .validate(|(tag, fields), e: &mut MapExtra<'a, '_>, emitter| {
@@ -160,11 +153,7 @@ pub fn parser<'a>() -> impl Parser<'a, &'a str, &'a Term<'a, DeBruijn>, Extra<'a
             text::keyword("case")
                 .padded()
                 .ignore_then(term.clone().padded())
-                .then(
-                    term.padded()
-                        .repeated()
-                        .collect::<Vec<&Term<'_, DeBruijn>>>(),
-                )
+                .then(term.padded().repeated().collect::<Vec<&Term<'_, V>>>())
                 .delimited_by(just('('), just(')'))
                 .validate(|(tag, branches), e: &mut MapExtra<'a, '_>, emitter| {
                     let state = e.state();
@@ -186,7 +175,7 @@ pub fn parser<'a>() -> impl Parser<'a, &'a str, &'a Term<'a, DeBruijn>, Extra<'a
     })
 }
 
-pub fn builtin_from_str<'a>(arena: &'a Bump, name: &str) -> Option<&'a Term<'a, DeBruijn>> {
+pub fn builtin_from_str<'a, V>(arena: &'a Bump, name: &str) -> Option<&'a Term<'a, V>> {
     match name {
         "addInteger" => Some(Term::add_integer(arena)),
         "subtractInteger" => Some(Term::subtract_integer(arena)),
@@ -1,12 +1,20 @@
 use bumpalo::Bump;
 use chumsky::{input, prelude::*};
 
-use crate::program::Version;
+use crate::{
+    binder::{DeBruijn, Name},
+    program::Version,
+};
 
 pub struct State<'a> {
     pub arena: &'a Bump,
-    pub env: Vec<&'a str>,
+    /// Names bound by the enclosing lambdas, innermost last, with their unique.
+    pub env: Vec<(&'a str, usize)>,
     pub version: Option<Version<'a>>,
+    /// Report unbound variables instead of parsing them as an index that
+    /// only fails once evaluated.
+    pub strict: bool,
+    next_unique: usize,
 }
 
 impl<'a> State<'a> {
@@ -15,9 +23,43 @@ impl<'a> State<'a> {
             arena,
             env: Vec::new(),
             version: None,
+            strict: false,
+            next_unique: 0,
         }
     }
 
+    pub fn strict(arena: &'a Bump) -> Self {
+        Self {
+            strict: true,
+            ..Self::new(arena)
+        }
+    }
+
+    pub fn fresh_unique(&mut self) -> usize {
+        let unique = self.next_unique;
+
+        self.next_unique += 1;
+
+        unique
+    }
+
+    /// Brings `name` into scope, the caller pops it once the lambda body is parsed.
+    pub fn bind(&mut self, name: &'a str) -> usize {
+        let unique = self.fresh_unique();
+
+        self.env.push((name, unique));
+
+        unique
+    }
+
+    /// The position in `env` and unique of the closest binder named `name`.
+    pub fn lookup(&self, name: &str) -> Option<(usize, usize)> {
+        self.env
+            .iter()
+            .rposition(|(x, _)| *x == name)
+            .map(|position| (position, self.env[position].1))
+    }
+
     pub fn set_version(&mut self, version: Version<'a>) {
         self.version = Some(version);
     }
@@ -29,5 +71,47 @@ impl<'a> State<'a> {
     }
 }
 
+/// A binder the parser can produce from a textual name.
+pub trait ParseBinder<'a>: 'a {
+    fn parameter(state: &mut State<'a>, name: &'a str) -> &'a Self;
+
+    fn var(state: &mut State<'a>, name: &'a str) -> &'a Self;
+}
+
+impl<'a> ParseBinder<'a> for DeBruijn {
+    fn parameter(state: &mut State<'a>, name: &'a str) -> &'a Self {
+        state.bind(name);
+
+        DeBruijn::zero(state.arena)
+    }
+
+    fn var(state: &mut State<'a>, name: &'a str) -> &'a Self {
+        match state.lookup(name) {
+            Some((position, _)) => DeBruijn::new(state.arena, state.env.len() - position),
+            // this will fail at eval time
+            // the conformance tests don't expect this
+            // to fail at parse time
+            None => DeBruijn::zero(state.arena),
+        }
+    }
+}
+
+impl<'a> ParseBinder<'a> for Name<'a> {
+    fn parameter(state: &mut State<'a>, name: &'a str) -> &'a Self {
+        let unique = state.bind(name);
+
+        Name::new(state.arena, name, unique)
+    }
+
+    fn var(state: &mut State<'a>, name: &'a str) -> &'a Self {
+        let unique = match state.lookup(name) {
+            Some((_, unique)) => unique,
+            None => state.fresh_unique(),
+        };
+
+        Name::new(state.arena, name, unique)
+    }
+}
+
 pub type Extra<'a> = extra::Full<Rich<'a, char>, State<'a>, ()>;
 pub type MapExtra<'a, 'b> = input::MapExtra<'a, 'b, &'a str, Extra<'a>>;
@@ -36,4 +36,23 @@ fn run_test(file_contents: &str, expected_output: &str, expected_budget: &str) {
     pretty_assertions::assert_eq!(consumed_budget, expected_budget);
 }
 
+fn run_round_trip_test(file_contents: &str, expected_output: &str) {
+    let arena = bumpalo::Bump::new();
+
+    let program = match uplc_turbo::syn::parse_program(&arena, file_contents).into_result() {
+        Ok(program) => program,
+        Err(_) if expected_output == "parse error" => return,
+        Err(errs) => panic!("failed to parse program\n{errs:#?}"),
+    };
+
+    let printed = program.to_pretty();
+
+    let reparsed = match uplc_turbo::syn::parse_program(&arena, &printed).into_result() {
+        Ok(reparsed) => reparsed,
+        Err(errs) => panic!("failed to reparse printed program\n{printed}\n{errs:#?}"),
+    };
+
+    pretty_assertions::assert_eq!(program, reparsed);
+}
+
 generate_tests!("conformance");
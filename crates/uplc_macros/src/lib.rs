@@ -35,6 +35,11 @@ pub fn generate_tests(input: TokenStream) -> TokenStream {
 
             let test_ident = Ident::new(&test_name, proc_macro2::Span::call_site());
 
+            let round_trip_ident = Ident::new(
+                &format!("{test_name}_round_trip"),
+                proc_macro2::Span::call_site(),
+            );
+
             let file_contents = fs::read_to_string(path).expect("Failed to read file");
 
             let expected_contents = fs::read_to_string(path.with_extension("uplc.expected"))
@@ -52,6 +57,11 @@ pub fn generate_tests(input: TokenStream) -> TokenStream {
                         #expected_budget,
                     );
                 }
+
+                #[test]
+                fn #round_trip_ident() {
+                    run_round_trip_test(#file_contents, #expected_contents);
+                }
             };
 
             test_functions.push(test_fn);